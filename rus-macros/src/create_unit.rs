@@ -1,13 +1,20 @@
+use crate::expr::UnitExpr;
 use proc_macro::TokenStream;
-use proc_macro2::{Literal, TokenStream as TokenStream2};
-use quote::quote;
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
 use syn::{
+    bracketed,
+    ext::IdentExt,
     parse::{Parse, ParseStream},
-    parse_macro_input, parse_quote, Attribute, BinOp, Error, Expr, Ident, LitStr, Result, Token,
-    Type,
+    parse_macro_input, parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    token, Attribute, Error, Ident, Lit, LitBool, LitFloat, LitStr, Result, Token, Type,
 };
 
-const METRIC_PREFIXES: [(f32, (&str, &str)); 21] = [
+const METRIC_PREFIXES: [(f32, (&str, &str)); 25] = [
+    (1e30, ("Q", "quetta")),
+    (1e27, ("R", "ronna")),
     (1e24, ("Y", "yotta")),
     (1e21, ("Z", "zetta")),
     (1e18, ("E", "exa")),
@@ -22,16 +29,34 @@ const METRIC_PREFIXES: [(f32, (&str, &str)); 21] = [
     (1e-1, ("d", "deci")),
     (1e-2, ("c", "centi")),
     (1e-3, ("m", "milli")),
-    // (1e-6, ("μ", "micro")),
-    (1e-6, ("u", "micro")),
+    (1e-6, ("μ", "micro")),
     (1e-9, ("n", "nano")),
     (1e-12, ("p", "pico")),
     (1e-15, ("f", "femto")),
     (1e-18, ("a", "atto")),
     (1e-21, ("z", "zepto")),
     (1e-24, ("y", "yocto")),
+    (1e-27, ("r", "ronto")),
+    (1e-30, ("q", "quecto")),
 ];
 
+/// ASCII spellings of prefix symbols, used for identifiers.
+const ASCII_PREFIXES: [(&str, &str); 1] = [("μ", "u")];
+
+fn ascii_prefix(abbr: &str) -> &str {
+    ASCII_PREFIXES
+        .iter()
+        .find(|(unicode, _)| *unicode == abbr)
+        .map_or(abbr, |(_, ascii)| ascii)
+}
+
+/// Other spellings of prefix symbols, accepted as alternative abbreviations. The micro sign (U+00B5)
+/// looks the same as the Greek letter mu which is the symbol of micro.
+const ALTERNATIVE_PREFIXES: [(&str, &str); 1] = [("μ", "\u{b5}")];
+
+/// Prefixes whose power of ten is not a multiple of three.
+const NON_ENGINEERING_PREFIXES: [&str; 4] = ["h", "da", "d", "c"];
+
 #[inline]
 pub fn create_unit(input: TokenStream) -> TokenStream {
     let UnitDef {
@@ -39,24 +64,46 @@ pub fn create_unit(input: TokenStream) -> TokenStream {
         docs,
         ident,
         abbreviation,
+        ascii,
+        abbreviations,
         name,
         tipe,
         initializer,
+        prefixes,
+        aliases,
+        plural,
+        exact,
     } = parse_macro_input!(input as UnitDef);
-    let scale = match create_scale(&initializer) {
-        Ok(s) => s,
-        Err(e) => return e.to_compile_error().into(),
-    };
+    if let Some(prefixes) = prefixes {
+        return Error::new(
+            prefixes.span(),
+            "`prefixes` is only supported by `create_unit_with_prefixes!`",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let scale = create_scale(&initializer);
+    let exact_scale = create_unit_exact_scale(&initializer, exact);
+    let kind_check = create_kind_check(&tipe, &initializer);
+    let alias_names = create_alias_names(&aliases);
+    let plural = create_plural(plural.as_ref(), "");
+    let aliases = create_aliases(&attrs, &ident, &tipe, &aliases);
+    let alternatives = create_alternatives(&abbreviation, ascii.as_ref(), &abbreviations, "");
+    let ascii = create_ascii(&abbreviation, ascii.as_ref());
+    // Numbers may have more digits than an `f32` holds, since they are kept exactly as well.
     quote! {
+        #kind_check
         #(#attrs)*
         #(#docs)*
-        #[allow(non_upper_case_globals)]
-        pub const #ident: #tipe = #tipe {
-            _kind_marker: ::std::marker::PhantomData,
-            abbreviation: #abbreviation,
-            name: #name,
-            scale: #scale,
-        };
+        #[allow(non_upper_case_globals, clippy::excessive_precision)]
+        pub const #ident: #tipe =
+            <#tipe>::new(&<#tipe>::info(#scale, #abbreviation, #name)
+                .with_exact_scale(#exact_scale)
+                #ascii
+                #alternatives
+                #alias_names
+                #plural);
+        #aliases
     }
     .into()
 }
@@ -64,73 +111,325 @@ pub fn create_unit(input: TokenStream) -> TokenStream {
 #[inline]
 pub fn create_unit_with_prefixes(input: TokenStream) -> TokenStream {
     let unit_def = parse_macro_input!(input as UnitDef);
-    let scale = match create_scale(&unit_def.initializer) {
-        Ok(s) => s,
+    let scale = create_scale(&unit_def.initializer);
+    let exact_scale = create_unit_exact_scale(&unit_def.initializer, unit_def.exact);
+    let kind_check = create_kind_check(&unit_def.tipe, &unit_def.initializer);
+    let prefixes = match unit_def
+        .prefixes
+        .as_ref()
+        .map_or(Ok(METRIC_PREFIXES.to_vec()), PrefixSelection::resolve)
+    {
+        Ok(p) => p,
         Err(e) => return e.to_compile_error().into(),
     };
-    let units = METRIC_PREFIXES
-        .into_iter()
-        .map(|(scale_factor, prefix)| create_unit_prefix(&unit_def, prefix, &scale, scale_factor));
+    let attrs = &unit_def.attrs;
+    let family = Ident::new(
+        &format!("__{}_PREFIXES", unit_def.ident.unraw()),
+        unit_def.ident.span(),
+    );
+    let tipe = &unit_def.tipe;
+    let members = prefixes.iter().map(|(scale_factor, prefix)| {
+        let power = scale_factor.log10().round() as i8;
+        let exponent = Literal::i32_unsuffixed(power.into());
+        let (abbreviation, ascii, name) = prefixed_names(&unit_def, *prefix);
+        let alternatives = create_alternatives(
+            &unit_def.abbreviation,
+            unit_def.ascii.as_ref(),
+            &unit_def.abbreviations,
+            prefix.0,
+        );
+        let ascii = create_ascii(&abbreviation, Some(&ascii));
+        let alias_names = create_alias_names(&prefixed_aliases(&unit_def, *prefix));
+        let plural = create_plural(unit_def.plural.as_ref(), prefix.1);
+        quote! {
+            ::rus::prefix::PrefixedUnit {
+                power: #power,
+                info: &<#tipe>::info(#scale_factor * (#scale), #abbreviation, #name)
+                    .with_exact_scale(::rus::__private::exact_mul(
+                        ::core::option::Option::Some(::rus::Ratio::new(1, 1, #exponent)),
+                        #exact_scale,
+                    ))
+                    #ascii
+                    #alternatives
+                    #alias_names
+                    #plural
+                    .with_prefixes(&#family),
+            }
+        }
+    });
+    let len = prefixes.len();
+    let units = prefixes
+        .iter()
+        .enumerate()
+        .map(|(index, (scale_factor, prefix))| {
+            create_unit_prefix(&unit_def, *prefix, *scale_factor, &family, index)
+        });
+    // Numbers may have more digits than an `f32` holds, since they are kept exactly as well.
     quote! {
+        #kind_check
+        #(#attrs)*
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals, clippy::excessive_precision)]
+        static #family: [::rus::prefix::PrefixedUnit; #len] = [#(#members),*];
         #(#units)*
     }
     .into()
 }
 
+/// The abbreviation, ASCII abbreviation and name of `unit_def` with `prefix` applied.
+fn prefixed_names(unit_def: &UnitDef, prefix: (&str, &str)) -> (LitStr, LitStr, LitStr) {
+    let abbreviation = LitStr::new(
+        &format!("{}{}", prefix.0, unit_def.abbreviation.value()),
+        unit_def.ident.span(),
+    );
+    let ascii = unit_def.ascii.as_ref().unwrap_or(&unit_def.abbreviation);
+    let ascii = LitStr::new(
+        &format!("{}{}", ascii_prefix(prefix.0), ascii.value()),
+        unit_def.ident.span(),
+    );
+    let name = LitStr::new(
+        &format!("{}{}", prefix.1, unit_def.name.value()),
+        unit_def.ident.span(),
+    );
+    (abbreviation, ascii, name)
+}
+
+/// The call setting the alternative abbreviations of a unit with `prefix` applied, if it has any.
+///
+/// These are every combination of a spelling of the prefix with the abbreviation, its ASCII
+/// spelling or one of the `abbreviations` given, other than the abbreviation itself. ASCII
+/// spellings of prefixes are only combined with ASCII spellings of the unit.
+fn create_alternatives(
+    abbreviation: &LitStr,
+    ascii: Option<&LitStr>,
+    abbreviations: &[LitStr],
+    prefix: &str,
+) -> TokenStream2 {
+    let canonical = format!("{prefix}{}", abbreviation.value());
+    let bases: Vec<_> = std::iter::once(abbreviation)
+        .chain(ascii)
+        .chain(abbreviations)
+        .map(LitStr::value)
+        .collect();
+    let prefixes = [prefix, ascii_prefix(prefix)].into_iter().chain(
+        ALTERNATIVE_PREFIXES
+            .iter()
+            .filter(|(symbol, _)| *symbol == prefix)
+            .map(|(_, alternative)| *alternative),
+    );
+    let mut spellings: Vec<String> = Vec::new();
+    for spelling_prefix in prefixes {
+        for base in &bases {
+            if spelling_prefix != prefix && spelling_prefix.is_ascii() && !base.is_ascii() {
+                continue;
+            }
+            let spelling = format!("{spelling_prefix}{base}");
+            if spelling != canonical && !spellings.contains(&spelling) {
+                spellings.push(spelling);
+            }
+        }
+    }
+    if spellings.is_empty() {
+        return TokenStream2::new();
+    }
+    quote! {.with_abbreviations(&[#(#spellings),*])}
+}
+
+/// The call setting the ASCII abbreviation of a unit, if it differs from the abbreviation.
+fn create_ascii(abbreviation: &LitStr, ascii: Option<&LitStr>) -> TokenStream2 {
+    match ascii {
+        Some(ascii) if ascii.value() != abbreviation.value() => {
+            quote! {.with_ascii_abbreviation(#ascii)}
+        }
+        _ => TokenStream2::new(),
+    }
+}
+
 fn create_unit_prefix(
     unit_def: &UnitDef,
     prefix: (&'static str, &'static str),
-    original_scale: &TokenStream2,
     scale_factor: f32,
+    family: &Ident,
+    index: usize,
 ) -> TokenStream2 {
     let attrs = &unit_def.attrs;
-    let new_docs = change_prefix_docs(
-        &unit_def.name.value(),
-        &unit_def.ident,
-        scale_factor,
-        prefix,
-    );
+    let new_docs = change_prefix_docs(unit_def.name.value(), &unit_def.ident, scale_factor, prefix);
     let docs = if new_docs.is_empty() {
         &unit_def.docs
     } else {
         &new_docs
     };
     let ident = Ident::new_raw(
-        &format!("{}{}", prefix.0, unit_def.ident),
+        &format!("{}{}", ascii_prefix(prefix.0), unit_def.ident),
         unit_def.ident.span(),
     );
     let tipe = unit_def.tipe.clone();
-    let abbreviation = LitStr::new(
-        &format!("{}{}", prefix.0, unit_def.abbreviation.value()),
-        unit_def.ident.span(),
-    );
-    let name = LitStr::new(
-        &format!("{}{}", prefix.1, unit_def.name.value()),
-        unit_def.ident.span(),
-    );
-    let scale = quote! {#scale_factor * (#original_scale)};
+    let aliases = prefixed_aliases(unit_def, prefix);
+    let aliases = create_aliases(attrs, &ident, &tipe, &aliases);
 
     quote! {
         #[allow(non_upper_case_globals)]
         #(#attrs)*
         #(#docs)*
-        pub const #ident: #tipe = #tipe {
-            _kind_marker: ::std::marker::PhantomData,
-            abbreviation: #abbreviation,
-            name: #name,
-            scale: #scale,
-        };
+        pub const #ident: #tipe = <#tipe>::new(#family[#index].info);
+        #aliases
+    }
+}
+
+/// The aliases of `unit_def` with `prefix` applied.
+///
+/// Only aliases which spell the abbreviation take prefixes, so `mol` gives `kmol` but the name
+/// `candela` gives no `kcandela`.
+fn prefixed_aliases(unit_def: &UnitDef, prefix: (&str, &str)) -> Vec<Ident> {
+    unit_def
+        .aliases
+        .iter()
+        .filter(|alias| prefix.0.is_empty() || is_abbreviation(unit_def, alias))
+        .map(|alias| {
+            Ident::new_raw(
+                &format!("{}{}", ascii_prefix(prefix.0), alias),
+                alias.span(),
+            )
+        })
+        .collect()
+}
+
+/// The call recording the aliases of a unit, so that they are found at runtime, if it has any.
+fn create_alias_names(aliases: &[Ident]) -> TokenStream2 {
+    if aliases.is_empty() {
+        return TokenStream2::new();
+    }
+    let names = aliases.iter().map(|alias| alias.unraw().to_string());
+    quote! {.with_aliases(&[#(#names),*])}
+}
+
+/// The call setting the plural of the name of a unit with the prefix `prefix_name` applied, if
+/// one was given.
+fn create_plural(plural: Option<&LitStr>, prefix_name: &str) -> TokenStream2 {
+    match plural {
+        Some(plural) => {
+            let plural = LitStr::new(&format!("{prefix_name}{}", plural.value()), plural.span());
+            quote! {.with_plural(#plural)}
+        }
+        None => TokenStream2::new(),
     }
 }
 
+/// Whether `alias` is a spelling of the abbreviation of `unit_def`, rather than a name.
+fn is_abbreviation(unit_def: &UnitDef, alias: &Ident) -> bool {
+    let alias = alias.unraw().to_string();
+    std::iter::once(&unit_def.abbreviation)
+        .chain(&unit_def.ascii)
+        .chain(&unit_def.abbreviations)
+        .any(|abbreviation| abbreviation.value() == alias)
+}
+
+/// Create additional identifiers which share the definition of the unit `ident`.
+fn create_aliases(
+    attrs: &[Attribute],
+    ident: &Ident,
+    tipe: &Type,
+    aliases: &[Ident],
+) -> TokenStream2 {
+    let doc = Literal::string(&format!(
+        "An alias of [`{0}`].\n\n[`{0}`]: {0}",
+        ident.unraw()
+    ));
+    aliases
+        .iter()
+        .map(|alias| {
+            quote! {
+                #[allow(non_upper_case_globals)]
+                #(#attrs)*
+                #[doc = #doc]
+                pub const #alias: #tipe = #ident;
+            }
+        })
+        .collect()
+}
+
 struct UnitDef {
     attrs: Vec<Attribute>,
     docs: Vec<Attribute>,
     ident: Ident,
     abbreviation: LitStr,
+    ascii: Option<LitStr>,
+    abbreviations: Vec<LitStr>,
     name: LitStr,
     tipe: Type,
-    initializer: Expr,
+    initializer: UnitExpr,
+    prefixes: Option<PrefixSelection>,
+    aliases: Vec<Ident>,
+    plural: Option<LitStr>,
+    exact: bool,
+}
+
+/// Which metric prefixes `create_unit_with_prefixes!` should generate.
+///
+/// The unprefixed unit is always generated.
+enum PrefixSelection {
+    /// `prefixes = all`: every prefix in [`METRIC_PREFIXES`].
+    All(Ident),
+    /// `prefixes = engineering`: only prefixes which are a power of one thousand.
+    Engineering(Ident),
+    /// `prefixes = [k, M, G]`: an explicit list of prefix symbols.
+    List(Span, Vec<Ident>),
+}
+
+impl PrefixSelection {
+    fn span(&self) -> Span {
+        match self {
+            Self::All(ident) | Self::Engineering(ident) => ident.span(),
+            Self::List(span, _) => *span,
+        }
+    }
+
+    fn resolve(&self) -> Result<Vec<(f32, (&'static str, &'static str))>> {
+        let is_selected: Box<dyn Fn(&str) -> bool> = match self {
+            Self::All(_) => Box::new(|_| true),
+            Self::Engineering(_) => Box::new(|abbr| !NON_ENGINEERING_PREFIXES.contains(&abbr)),
+            Self::List(_, idents) => {
+                for ident in idents {
+                    if !METRIC_PREFIXES
+                        .iter()
+                        .any(|(_, (abbr, _))| !abbr.is_empty() && ident == ascii_prefix(abbr))
+                    {
+                        return Err(Error::new_spanned(
+                            ident,
+                            format!("unknown metric prefix `{ident}`"),
+                        ));
+                    }
+                }
+                Box::new(move |abbr| idents.iter().any(|ident| ident == ascii_prefix(abbr)))
+            }
+        };
+        Ok(METRIC_PREFIXES
+            .into_iter()
+            .filter(|(_, (abbr, _))| abbr.is_empty() || is_selected(abbr))
+            .collect())
+    }
+}
+
+impl Parse for PrefixSelection {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(token::Bracket) {
+            let content;
+            let brackets = bracketed!(content in input);
+            let idents = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+            return Ok(Self::List(brackets.span, idents.into_iter().collect()));
+        }
+        let ident = input.parse::<Ident>()?;
+        if ident == "all" {
+            Ok(Self::All(ident))
+        } else if ident == "engineering" {
+            Ok(Self::Engineering(ident))
+        } else {
+            Err(Error::new_spanned(
+                ident,
+                "expected `all`, `engineering` or a list of prefixes such as `[k, M, G]`",
+            ))
+        }
+    }
 }
 
 impl Parse for UnitDef {
@@ -152,55 +451,246 @@ impl Parse for UnitDef {
         input.parse::<Token![:]>()?;
         let tipe = input.parse::<Type>()?;
         input.parse::<Token![=]>()?;
-        let initializer = input.parse::<Expr>()?;
+        let initializer = input.parse::<UnitExpr>()?;
         input.parse::<Token![,]>()?;
         let name = input.parse::<LitStr>()?;
         let abbreviation = match input.parse::<LitStr>() {
             Ok(a) => a,
             Err(_) => LitStr::new(&ident.to_string(), ident.span()),
         };
+        let mut prefixes = None;
+        let mut aliases = None;
+        let mut ascii = None;
+        let mut abbreviations = None;
+        let mut exact = None;
+        let mut plural = None;
+        while input.parse::<Token![,]>().is_ok() && !input.is_empty() {
+            let option = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            if (option == "prefixes" && prefixes.is_some())
+                || (option == "aliases" && aliases.is_some())
+                || (option == "ascii" && ascii.is_some())
+                || (option == "abbreviations" && abbreviations.is_some())
+                || (option == "exact" && exact.is_some())
+                || (option == "plural" && plural.is_some())
+            {
+                return Err(Error::new_spanned(
+                    &option,
+                    format!("`{option}` given more than once"),
+                ));
+            } else if option == "prefixes" {
+                prefixes = Some(input.parse()?);
+            } else if option == "aliases" {
+                let content;
+                bracketed!(content in input);
+                let idents = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                aliases = Some(idents.into_iter().collect());
+            } else if option == "ascii" {
+                let lit = input.parse::<LitStr>()?;
+                if !lit.value().is_ascii() {
+                    return Err(Error::new_spanned(lit, "`ascii` must only contain ASCII"));
+                }
+                ascii = Some(lit);
+            } else if option == "abbreviations" {
+                let content;
+                bracketed!(content in input);
+                let lits = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+                abbreviations = Some(lits.into_iter().collect());
+            } else if option == "plural" {
+                plural = Some(input.parse::<LitStr>()?);
+            } else if option == "exact" {
+                exact = Some(input.parse::<LitBool>()?.value);
+            } else {
+                return Err(Error::new_spanned(
+                    &option,
+                    format!("unknown unit option `{option}`"),
+                ));
+            }
+        }
+        if ascii.is_none() && !abbreviation.value().is_ascii() {
+            return Err(Error::new_spanned(
+                abbreviation,
+                "a non-ASCII abbreviation needs an ASCII spelling, given with `ascii = \"...\"`",
+            ));
+        }
         Ok(Self {
             attrs,
             docs,
             ident,
             abbreviation,
+            ascii,
+            abbreviations: abbreviations.unwrap_or_default(),
             name,
             tipe,
             initializer,
+            prefixes,
+            aliases: aliases.unwrap_or_default(),
+            plural,
+            exact: exact.unwrap_or(true),
         })
     }
 }
 
-fn create_scale(expr: &Expr) -> Result<TokenStream2> {
+fn create_scale(expr: &UnitExpr) -> TokenStream2 {
+    match expr {
+        UnitExpr::Mul(left, right) => {
+            let left = create_scale(left);
+            let right = create_scale(right);
+            quote! {
+                (#left) * (#right)
+            }
+        }
+        UnitExpr::Div(left, right) => {
+            let left = create_scale(left);
+            let right = create_scale(right);
+            quote! {
+                (#left) / (#right)
+            }
+        }
+        UnitExpr::Pow(base, power) => {
+            let base = create_scale(base);
+            let factors = std::iter::repeat_n(&base, power.unsigned_abs() as usize);
+            if *power < 0 {
+                quote! {
+                    1. #(/ (#factors))*
+                }
+            } else {
+                quote! {
+                    1. #(* (#factors))*
+                }
+            }
+        }
+        UnitExpr::Lit(Lit::Int(lit)) => {
+            let lit = LitFloat::new(&format!("{}.", lit.base10_digits()), lit.span());
+            quote! {#lit}
+        }
+        UnitExpr::Lit(Lit::Float(lit)) => {
+            let lit = LitFloat::new(lit.base10_digits(), lit.span());
+            quote! {#lit}
+        }
+        UnitExpr::Lit(lit) => Error::new_spanned(lit, "expected a number").to_compile_error(),
+        UnitExpr::Path(path) => quote! {#path.scale()},
+    }
+}
+
+/// Create the exact scale of a unit defined as `expr`, which is `None` if the unit was marked
+/// with `exact = false`.
+fn create_unit_exact_scale(expr: &UnitExpr, exact: bool) -> TokenStream2 {
+    if exact {
+        create_exact_scale(expr)
+    } else {
+        quote! {::core::option::Option::None}
+    }
+}
+
+/// Create the exact scale of `expr`, as an `Option<Ratio>` which is `None` if a number in it is
+/// too long to be held exactly or a unit in it has no exact scale.
+fn create_exact_scale(expr: &UnitExpr) -> TokenStream2 {
+    match expr {
+        UnitExpr::Mul(left, right) => {
+            let left = create_exact_scale(left);
+            let right = create_exact_scale(right);
+            quote! {
+                ::rus::__private::exact_mul(#left, #right)
+            }
+        }
+        UnitExpr::Div(left, right) => {
+            let left = create_exact_scale(left);
+            let right = create_exact_scale(right);
+            quote! {
+                ::rus::__private::exact_div(#left, #right)
+            }
+        }
+        UnitExpr::Pow(base, power) => {
+            let base = create_exact_scale(base);
+            quote! {
+                ::rus::__private::exact_powi(#base, #power)
+            }
+        }
+        UnitExpr::Lit(Lit::Int(lit)) => exact_decimal(lit.base10_digits()),
+        UnitExpr::Lit(Lit::Float(lit)) => exact_decimal(lit.base10_digits()),
+        // The error is reported by `create_scale`.
+        UnitExpr::Lit(_) => quote! {::core::option::Option::None},
+        UnitExpr::Path(path) => quote! {#path.exact_scale()},
+    }
+}
+
+/// Create the exact value of a decimal number such as `1.25e-3`, as an `Option<Ratio>`.
+fn exact_decimal(digits: &str) -> TokenStream2 {
+    let (mantissa, exponent) = digits.split_once(['e', 'E']).unwrap_or((digits, "0"));
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let numerator = format!("{integer}{fraction}").parse::<u128>();
+    let exponent = exponent
+        .parse::<i32>()
+        .ok()
+        .and_then(|exponent| exponent.checked_sub(fraction.len().try_into().ok()?));
+    match (numerator, exponent) {
+        (Ok(numerator), Some(exponent)) if numerator != 0 => {
+            let numerator = Literal::u128_unsuffixed(numerator);
+            let exponent = Literal::i32_unsuffixed(exponent);
+            quote! {::core::option::Option::Some(::rus::Ratio::new(#numerator, 1, #exponent))}
+        }
+        _ => quote! {::core::option::Option::None},
+    }
+}
+
+/// Create an assertion that the kind of `expr` matches the kind of `tipe`.
+///
+/// An initializer made only of numbers is the scale of a base unit, and so is not checked.
+fn create_kind_check(tipe: &Type, expr: &UnitExpr) -> TokenStream2 {
+    if !expr.contains_path() {
+        return TokenStream2::new();
+    }
+    let kind = create_kind(expr);
+    quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            fn check_kind() {
+                ::rus::__private::assert_kind::<#tipe>(#kind);
+            }
+        };
+    }
+}
+
+fn create_kind(expr: &UnitExpr) -> TokenStream2 {
     match expr {
-        Expr::Binary(expr) => {
-            let left = create_scale(expr.left.as_ref())?;
-            let right = create_scale(expr.right.as_ref())?;
-            match expr.op {
-                BinOp::Mul(_) => Ok(quote! {
-                    (#left) * (#right)
-                }),
-                BinOp::Div(_) => Ok(quote! {
-                    (#left) / (#right)
-                }),
-                other => Err(Error::new_spanned(
-                    other,
-                    "Only supported operaors are `*` and `/`",
-                )),
-            }
-        }
-        Expr::Lit(expr) => Ok(quote! {#expr}),
-        Expr::Paren(expr) => {
-            let inside_scale = create_scale(expr.expr.as_ref())?;
-            Ok(quote! {
-                (#inside_scale)
-            })
-        }
-        Expr::Path(expr) => Ok(quote! {#expr.scale}),
-        _ => Err(Error::new_spanned(
-            expr,
-            "expected binary operator expression",
-        )),
+        UnitExpr::Mul(left, right) => {
+            let left = create_kind(left);
+            let right = create_kind(right);
+            quote! {
+                (#left) * (#right)
+            }
+        }
+        UnitExpr::Div(left, right) => {
+            let left = create_kind(left);
+            let right = create_kind(right);
+            quote! {
+                (#left) / (#right)
+            }
+        }
+        UnitExpr::Pow(base, power) => {
+            // Negative and zero powers are written as `base / base^(|power| + 1)`, so that no
+            // dimensionless kind needs to be divided.
+            let base = create_kind(base);
+            let repeats = power.unsigned_abs() as usize + usize::from(*power <= 0);
+            let mut product = base.clone();
+            for _ in 1..repeats {
+                product = quote! {
+                    (#product) * (#base)
+                };
+            }
+            if *power > 0 {
+                product
+            } else {
+                quote! {
+                    (#base) / (#product)
+                }
+            }
+        }
+        UnitExpr::Lit(_) => quote! {::rus::__private::dimensionless()},
+        UnitExpr::Path(path) => quote_spanned! {path.span()=>
+            ::rus::__private::kind_of(&#path)
+        },
     }
 }
 
@@ -0,0 +1,114 @@
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    token, Error, Ident, Lit, LitInt, Path, Result, Token,
+};
+
+/// An arithmetic expression of units or kinds.
+///
+/// ```text
+/// expr   := factor (('*' | '/') factor)*
+/// factor := atom ('^' power | '.' 'powi' '(' power ')')?
+/// atom   := path | number | '(' expr ')'
+/// power  := '-'? integer | '(' '-'? integer ')'
+/// ```
+///
+/// Rust's own expression grammar cannot be used, as it parses `^` with a lower precedence than `*`
+/// and `/`.
+pub enum UnitExpr {
+    Path(Path),
+    Lit(Lit),
+    Mul(Box<UnitExpr>, Box<UnitExpr>),
+    Div(Box<UnitExpr>, Box<UnitExpr>),
+    Pow(Box<UnitExpr>, i32),
+}
+
+impl UnitExpr {
+    /// Whether any paths (units or kinds) appear in the expression.
+    pub fn contains_path(&self) -> bool {
+        match self {
+            Self::Path(_) => true,
+            Self::Lit(_) => false,
+            Self::Mul(left, right) | Self::Div(left, right) => {
+                left.contains_path() || right.contains_path()
+            }
+            Self::Pow(base, _) => base.contains_path(),
+        }
+    }
+
+    fn parse_factor(input: ParseStream) -> Result<Self> {
+        let atom = Self::parse_atom(input)?;
+        if input.peek(Token![^]) {
+            input.parse::<Token![^]>()?;
+            Ok(Self::Pow(Box::new(atom), parse_power(input)?))
+        } else if input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            let method = input.parse::<Ident>()?;
+            if method != "powi" {
+                return Err(Error::new_spanned(method, "only `powi` is supported"));
+            }
+            let content;
+            parenthesized!(content in input);
+            let power = parse_power(&content)?;
+            if !content.is_empty() {
+                return Err(content.error("expected a single integer power"));
+            }
+            Ok(Self::Pow(Box::new(atom), power))
+        } else {
+            Ok(atom)
+        }
+    }
+
+    fn parse_atom(input: ParseStream) -> Result<Self> {
+        if input.peek(token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let inner = content.parse::<Self>()?;
+            if !content.is_empty() {
+                return Err(content.error("expected `*`, `/` or `^`"));
+            }
+            Ok(inner)
+        } else if input.peek(Lit) {
+            match input.parse::<Lit>()? {
+                lit @ (Lit::Int(_) | Lit::Float(_)) => Ok(Self::Lit(lit)),
+                other => Err(Error::new_spanned(other, "expected a number")),
+            }
+        } else if input.peek(Ident) || input.peek(Token![::]) {
+            Ok(Self::Path(input.parse()?))
+        } else {
+            Err(input.error("expected a path, number or parenthesized expression"))
+        }
+    }
+}
+
+impl Parse for UnitExpr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut expr = Self::parse_factor(input)?;
+        loop {
+            if input.peek(Token![*]) {
+                input.parse::<Token![*]>()?;
+                expr = Self::Mul(Box::new(expr), Box::new(Self::parse_factor(input)?));
+            } else if input.peek(Token![/]) {
+                input.parse::<Token![/]>()?;
+                expr = Self::Div(Box::new(expr), Box::new(Self::parse_factor(input)?));
+            } else {
+                return Ok(expr);
+            }
+        }
+    }
+}
+
+fn parse_power(input: ParseStream) -> Result<i32> {
+    if input.peek(token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        let power = parse_power(&content)?;
+        if !content.is_empty() {
+            return Err(content.error("expected a single integer power"));
+        }
+        return Ok(power);
+    }
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let power = input.parse::<LitInt>()?.base10_parse::<i32>()?;
+    Ok(if negative { -power } else { power })
+}
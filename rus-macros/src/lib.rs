@@ -1,18 +1,56 @@
 use proc_macro::TokenStream;
 mod create_unit;
+mod expr;
+mod static_unit;
 mod type_arith;
 
+/// Define a new unit as a `pub const`.
+///
+/// The syntax is `ident: UnitType = initializer, "name"`, optionally followed by an abbreviation
+/// string literal. The abbreviation defaults to the identifier. The initializer is an expression
+/// of numbers and other units using `*`, `/`, parentheses and integer powers (`s^2` or
+/// `s.powi(2)`), from which the scale of the unit is computed. The scale is also kept as an exact
+/// `Ratio` when every number in the initializer is a decimal short enough to hold exactly and every
+/// unit in it has an exact scale. A unit whose numbers are rounded, such as a multiple of π or a
+/// measured value, must be marked with `exact = false`, so that it has no exact scale.
+///
+/// Extra identifiers for the same unit can be given with `aliases = [mol]`. An abbreviation which
+/// is not ASCII, such as `"Ω"`, must be given an ASCII spelling with `ascii = "ohm"`, which is
+/// used when units are formatted with ASCII symbols. Other accepted spellings of the abbreviation
+/// can be given with `abbreviations = ["l"]`. A name whose plural is not made by adding an `s`
+/// can be given its plural with `plural = "feet"`.
 #[proc_macro]
 pub fn create_unit(input: TokenStream) -> TokenStream {
     create_unit::create_unit(input)
 }
 
+/// Compute a unit kind from an expression of other kinds using `*`, `/`, parentheses and integer
+/// powers, such as `LengthKind * MassKind / TimeKind^2`.
 #[proc_macro]
 pub fn type_arith(input: TokenStream) -> TokenStream {
     type_arith::type_arith(input)
 }
 
+/// Define a new unit along with a copy of it for each metric prefix.
+///
+/// Takes the same input as [`create_unit!`], optionally followed by `prefixes = all` (the
+/// default), `prefixes = engineering`, or an explicit list such as `prefixes = [k, M, G]`.
+///
+/// Aliases which are a spelling of the abbreviation, such as `mol` for `mole`, are given to every
+/// prefixed unit, so `kmol` is an alias of `kmole`. Other aliases, such as `candela` for `cd`, are
+/// names, and are only given to the unprefixed unit.
 #[proc_macro]
 pub fn create_unit_with_prefixes(input: TokenStream) -> TokenStream {
     create_unit::create_unit_with_prefixes(input)
 }
+
+/// Define a marker type for a unit which is known at compile time, for use with
+/// `rus::static_unit::StaticQuantity`.
+///
+/// The syntax is `Ident: UnitType = unit`, where `unit` is a constant expression of type
+/// `UnitType`, usually an existing unit such as `u::km`. The marker type is also a value, so that
+/// quantities can be created as `5. * Km`, or with a primitive integer scalar as `5_i32 * Km`.
+#[proc_macro]
+pub fn create_static_unit(input: TokenStream) -> TokenStream {
+    static_unit::create_static_unit(input)
+}
@@ -0,0 +1,81 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Attribute, Expr, Ident, Result, Token, Type, Visibility,
+};
+
+#[inline]
+pub fn create_static_unit(input: TokenStream) -> TokenStream {
+    let StaticUnitDef {
+        attrs,
+        vis,
+        ident,
+        tipe,
+        unit,
+    } = parse_macro_input!(input as StaticUnitDef);
+    let scalars = ["f32", "i8", "i16", "i32", "i64"].map(|scalar| Ident::new(scalar, ident.span()));
+    quote! {
+        #(#attrs)*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        #vis struct #ident;
+
+        impl ::rus::static_unit::StaticUnit for #ident {
+            type Kind = <#tipe as ::rus::__private::UnitOf>::Kind;
+
+            const SCALE: f32 = {
+                const UNIT: #tipe = #unit;
+                UNIT.scale()
+            };
+
+            const EXACT_SCALE: ::core::option::Option<::rus::Ratio> = {
+                const UNIT: #tipe = #unit;
+                UNIT.exact_scale()
+            };
+
+            fn unit() -> ::rus::CompositeUnit<Self::Kind> {
+                const UNIT: #tipe = #unit;
+                UNIT.into()
+            }
+        }
+
+        #(
+            impl ::core::ops::Mul<#ident> for #scalars {
+                type Output = ::rus::static_unit::StaticQuantity<#ident, #scalars>;
+
+                fn mul(self, _: #ident) -> Self::Output {
+                    ::rus::static_unit::StaticQuantity::new(self)
+                }
+            }
+        )*
+    }
+    .into()
+}
+
+struct StaticUnitDef {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    tipe: Type,
+    unit: Expr,
+}
+
+impl Parse for StaticUnitDef {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let tipe = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let unit = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            tipe,
+            unit,
+        })
+    }
+}
@@ -1,40 +1,57 @@
+use crate::expr::UnitExpr;
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, BinOp, Error, Expr, Result};
+use syn::{parse_macro_input, Error, Result};
 
 #[inline]
 pub fn type_arith(input: TokenStream) -> TokenStream {
-    let expr = parse_macro_input!(input as Expr);
+    let expr = parse_macro_input!(input as UnitExpr);
     match handle_expr(&expr) {
         Ok(result) => result.into(),
         Err(err) => err.to_compile_error().into(),
     }
 }
 
-fn handle_expr(expr: &Expr) -> Result<TokenStream2> {
+fn handle_expr(expr: &UnitExpr) -> Result<TokenStream2> {
     match expr {
-        Expr::Binary(expr) => {
-            let left = handle_expr(expr.left.as_ref())?;
-            let right = handle_expr(expr.right.as_ref())?;
-
-            match expr.op {
-                BinOp::Mul(_) => Ok(quote! {
-                    <#left as ::core::ops::Mul<#right>>::Output
-                }),
-                BinOp::Div(_) => Ok(quote! {
-                    <#left as ::core::ops::Div<#right>>::Output
-                }),
-                other => Err(Error::new_spanned(
-                    other,
-                    "only handling `*` and `/` operators",
-                )),
+        UnitExpr::Mul(left, right) => {
+            let left = handle_expr(left)?;
+            let right = handle_expr(right)?;
+            Ok(quote! {
+                <#left as ::core::ops::Mul<#right>>::Output
+            })
+        }
+        UnitExpr::Div(left, right) => {
+            let left = handle_expr(left)?;
+            let right = handle_expr(right)?;
+            Ok(quote! {
+                <#left as ::core::ops::Div<#right>>::Output
+            })
+        }
+        UnitExpr::Pow(base, power) => {
+            // Negative and zero powers are written as `base / base^(|power| + 1)`, so that no
+            // dimensionless kind needs to be named.
+            let base = handle_expr(base)?;
+            let repeats = power.unsigned_abs() as usize + usize::from(*power <= 0);
+            let mut product = base.clone();
+            for _ in 1..repeats {
+                product = quote! {
+                    <#product as ::core::ops::Mul<#base>>::Output
+                };
+            }
+            if *power > 0 {
+                Ok(product)
+            } else {
+                Ok(quote! {
+                    <#base as ::core::ops::Div<#product>>::Output
+                })
             }
         }
-        Expr::Path(expr) => Ok(quote! {#expr}),
-        _ => Err(Error::new_spanned(
-            expr,
-            "expected binary operator expression",
+        UnitExpr::Path(path) => Ok(quote! {#path}),
+        UnitExpr::Lit(lit) => Err(Error::new_spanned(
+            lit,
+            "numbers are not supported in `type_arith!`",
         )),
     }
 }
@@ -0,0 +1,229 @@
+//! Physical constants, with their values and standard uncertainties from several editions of the
+//! CODATA recommended values.
+//!
+//! Each constant is a [`SingleQuantity`] in SI base units of the right kind, with its value from
+//! the latest edition. Arithmetic with other quantities is checked at compile time as usual.
+//! ```
+//! use rus::constants::G;
+//! use rus::kind::ForceKind;
+//! use rus::unit_defs as u;
+//! use rus::SingleQuantity;
+//!
+//! let m1 = 5.972e24 * u::kg;
+//! let m2 = 70. * u::kg;
+//! let r = 6.371e3 * u::km;
+//! let weight: SingleQuantity<ForceKind> = G * m1 * m2 / (r * r);
+//! assert_eq!(format!("{:.0}", weight.to(u::N)), "687 N");
+//! ```
+//!
+//! The values from any edition, along with their standard uncertainties, are given by [`edition`].
+//! ```
+//! use rus::constants::{edition, Codata};
+//! assert_eq!(edition(Codata::Codata2022).k_B.scalar().sigma, 0.);
+//! assert_eq!(edition(Codata::Codata2014).k_B.scalar().sigma, 7.9e-30);
+//! ```
+//!
+//! Quantities hold `f32`s, which cannot hold the smallest uncertainties, such as the `8.1e-42`
+//! of the Planck constant in 2014, to full precision. The values and uncertainties of every
+//! edition are also given as `f64`s by [`values`].
+//! ```
+//! use rus::constants::{values, Codata};
+//! assert_eq!(values(Codata::Codata2014).h, (6.626_070_040e-34, 8.1e-42));
+//! ```
+#![allow(non_upper_case_globals)]
+
+use crate::kind::*;
+use crate::quantity::SingleQuantity;
+use crate::scalar::Uncertain;
+use crate::CompositeUnit;
+use rus_macros::type_arith;
+
+/// An edition of the CODATA recommended values of the fundamental physical constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Codata {
+    /// The 2014 values, the last before the redefinition of the SI base units.
+    Codata2014,
+    /// The 2018 values, the first in which h, e, k_B and N_A are exact.
+    Codata2018,
+    /// The 2022 values.
+    #[default]
+    Codata2022,
+}
+
+/// The value and standard uncertainty of a constant in SI base units, in each edition in order.
+type Values = [(f64, f64); 3];
+
+/// The value of a constant from the latest edition, as a quantity in SI base units.
+const fn latest<Kind: UnitKind>(values: Values) -> SingleQuantity<Kind> {
+    let (value, _) = values[Codata::Codata2022 as usize];
+    SingleQuantity::new(CompositeUnit::si(), value as f32)
+}
+
+/// The value of a constant from `edition` with its standard uncertainty, as a quantity in SI base
+/// units.
+const fn uncertain<Kind: UnitKind>(
+    values: Values,
+    edition: Codata,
+) -> SingleQuantity<Kind, Uncertain> {
+    let (value, uncertainty) = values[edition as usize];
+    SingleQuantity::new(
+        CompositeUnit::si(),
+        Uncertain::new(value as f32, uncertainty as f32),
+    )
+}
+
+/// Define each constant from its kind and values, along with its field in [`Constants`].
+macro_rules! constants {
+    ($($(#[$attr:meta])* $name:ident: $kind:ty = $values:expr;)*) => {
+        $(
+            $(#[$attr])*
+            pub const $name: SingleQuantity<$kind> = latest($values);
+        )*
+
+        /// The physical constants from one edition, with their standard uncertainties, as given
+        /// by [`edition`].
+        #[allow(non_snake_case)]
+        #[derive(Debug, Clone, Copy)]
+        pub struct Constants {
+            $(
+                $(#[$attr])*
+                pub $name: SingleQuantity<$kind, Uncertain>,
+            )*
+        }
+
+        /// The physical constants from `edition`, with their standard uncertainties.
+        /// ```
+        /// use rus::constants::{edition, Codata};
+        /// let g = edition(Codata::Codata2018).G;
+        /// assert_eq!(format!("{g:.2e}"), "6.67e-11 ± 1.50e-15 m^3 kg^-1 s^-2");
+        /// assert!(edition(Codata::Codata2014).G.scalar().value < g.scalar().value);
+        /// ```
+        pub const fn edition(edition: Codata) -> Constants {
+            Constants {
+                $($name: uncertain($values, edition),)*
+            }
+        }
+
+        /// The values and standard uncertainties of the physical constants from one edition, in
+        /// SI base units, as given by [`values`].
+        #[allow(non_snake_case)]
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct ConstantValues {
+            $(
+                $(#[$attr])*
+                pub $name: (f64, f64),
+            )*
+        }
+
+        /// The values and standard uncertainties of the physical constants from `edition`, as
+        /// `f64`s in SI base units.
+        pub const fn values(edition: Codata) -> ConstantValues {
+            ConstantValues {
+                $($name: $values[edition as usize],)*
+            }
+        }
+    };
+}
+
+constants! {
+    /// The speed of light in vacuum.
+    c: VelocityKind = [(299_792_458., 0.); 3];
+    /// The Newtonian constant of gravitation.
+    #[allow(clippy::type_complexity)]
+    G: type_arith!(LengthKind ^ 3 / (MassKind * TimeKind ^ 2)) = [
+        (6.674_08e-11, 3.1e-15),
+        (6.674_30e-11, 1.5e-15),
+        (6.674_30e-11, 1.5e-15),
+    ];
+    /// The Planck constant.
+    h: type_arith!(EnergyKind * TimeKind) = [
+        (6.626_070_040e-34, 8.1e-42),
+        (6.626_070_15e-34, 0.),
+        (6.626_070_15e-34, 0.),
+    ];
+    /// The reduced Planck constant, ħ.
+    hbar: type_arith!(EnergyKind * TimeKind) = [
+        (1.054_571_800e-34, 1.3e-42),
+        (1.054_571_817e-34, 0.),
+        (1.054_571_817e-34, 0.),
+    ];
+    /// The elementary charge.
+    e: ChargeKind = [
+        (1.602_176_620_8e-19, 9.8e-28),
+        (1.602_176_634e-19, 0.),
+        (1.602_176_634e-19, 0.),
+    ];
+    /// The Boltzmann constant.
+    k_B: type_arith!(EnergyKind / TemperatureKind) = [
+        (1.380_648_52e-23, 7.9e-30),
+        (1.380_649e-23, 0.),
+        (1.380_649e-23, 0.),
+    ];
+    /// The Avogadro constant.
+    N_A: type_arith!(AmountKind ^ -1) = [
+        (6.022_140_857e23, 7.4e15),
+        (6.022_140_76e23, 0.),
+        (6.022_140_76e23, 0.),
+    ];
+    /// The molar gas constant.
+    R: type_arith!(EnergyKind / (AmountKind * TemperatureKind)) = [
+        (8.314_459_8, 4.8e-6),
+        (8.314_462_618, 0.),
+        (8.314_462_618, 0.),
+    ];
+    /// The Faraday constant.
+    F: type_arith!(ChargeKind / AmountKind) = [
+        (96_485.332_89, 5.9e-4),
+        (96_485.332_12, 0.),
+        (96_485.332_12, 0.),
+    ];
+    /// The Stefan–Boltzmann constant, σ.
+    #[allow(clippy::type_complexity)]
+    sigma: type_arith!(PowerKind / (AreaKind * TemperatureKind ^ 4)) = [
+        (5.670_367e-8, 1.3e-13),
+        (5.670_374_419e-8, 0.),
+        (5.670_374_419e-8, 0.),
+    ];
+    /// The vacuum electric permittivity, ε₀.
+    epsilon_0: type_arith!(ChargeKind ^ 2 / (ForceKind * AreaKind)) = [
+        (8.854_187_817e-12, 0.),
+        (8.854_187_812_8e-12, 1.3e-21),
+        (8.854_187_818_8e-12, 1.4e-21),
+    ];
+    /// The vacuum magnetic permeability, μ₀.
+    mu_0: type_arith!(ForceKind / CurrentKind ^ 2) = [
+        (1.256_637_061_4e-6, 0.),
+        (1.256_637_062_12e-6, 1.9e-16),
+        (1.256_637_061_27e-6, 2.0e-16),
+    ];
+    /// The mass of an electron.
+    m_e: MassKind = [
+        (9.109_383_56e-31, 1.1e-38),
+        (9.109_383_701_5e-31, 2.8e-40),
+        (9.109_383_713_9e-31, 2.8e-40),
+    ];
+    /// The mass of a proton.
+    m_p: MassKind = [
+        (1.672_621_898e-27, 2.1e-35),
+        (1.672_621_923_69e-27, 5.1e-37),
+        (1.672_621_925_95e-27, 5.2e-37),
+    ];
+    /// The mass of a neutron.
+    m_n: MassKind = [
+        (1.674_927_471e-27, 2.1e-35),
+        (1.674_927_498_04e-27, 9.5e-37),
+        (1.674_927_500_56e-27, 8.5e-37),
+    ];
+    /// The fine-structure constant, α.
+    alpha: DimensionlessKind = [
+        (7.297_352_566_4e-3, 1.7e-12),
+        (7.297_352_569_3e-3, 1.1e-12),
+        (7.297_352_564_3e-3, 1.1e-12),
+    ];
+    /// The Bohr radius.
+    a_0: LengthKind = [
+        (5.291_772_106_7e-11, 1.2e-20),
+        (5.291_772_109_03e-11, 8.0e-21),
+        (5.291_772_105_44e-11, 8.2e-21),
+    ];
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(
     missing_docs,
     clippy::missing_errors_doc,
@@ -35,9 +36,110 @@
 //! let velocity2 = 0.06 * u::m / u::s;
 //! assert_eq!(velocity1, velocity2);
 //! ```
+//!
+//! Crates can define their own units with [`create_unit!`] and [`create_unit_with_prefixes!`].
+//! ```
+//! use rus::kinds::{LengthUnit, TimeUnit};
+//! use rus::unit_defs as u;
+//! use rus::{create_unit, create_unit_with_prefixes};
+//!
+//! create_unit!(
+//!     /// An international foot.
+//!     ft: LengthUnit = 0.3048 * u::m,
+//!     "foot"
+//! );
+//! create_unit_with_prefixes!(
+//!     /// A shake, ten nanoseconds.
+//!     shake: TimeUnit = 10. * u::ns,
+//!     "shake",
+//!     prefixes = [k, M]
+//! );
+//!
+//! assert_eq!(3. * ft, 0.9144 * u::m);
+//! assert_eq!(1. * kshake, 10. * u::us);
+//! ```
+//!
+//! The kind of the initializer is checked against the declared unit type at compile time.
+//! ```compile_fail
+//! use rus::kinds::ForceUnit;
+//! use rus::unit_defs as u;
+//!
+//! rus::create_unit!(
+//!     /// Not actually a force.
+//!     bad: ForceUnit = u::kg * u::m / u::s,
+//!     "bad"
+//! );
+//! ```
+//!
+//! The `std` feature is enabled by default. Without it the crate is `no_std`, and does not need
+//! `alloc` either, since no part of it allocates. The floating point functions which `std`
+//! provides then come from [`libm`](https://docs.rs/libm), so the `libm` feature must be enabled
+//! instead.
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
+extern crate self as rus;
+
+pub mod constants;
+mod math;
 mod quantity;
 pub use quantity::SingleQuantity;
+pub mod scalar;
+pub mod static_unit;
 mod unit;
-pub use unit::single::unit_defs;
-pub use unit::{composite::CompositeUnit, single::SingleUnit};
+pub use rus_macros::{create_static_unit, create_unit, create_unit_with_prefixes, type_arith};
+#[doc(no_inline)]
+pub use typenum;
+pub use unit::single::{kinds, unit_defs};
+pub use unit::{cgs, imperial, us_customary};
+pub use unit::{
+    composite::CompositeUnit, single::SingleUnit, DynUnit, ParseUnitError, Ratio, UnitInfo,
+};
+pub use unit::{format, kind, prefix};
+
+#[doc(hidden)]
+pub mod __private {
+    //! Items used by the code generated by the macros. Not public API.
+    use crate::kind::{DimensionlessKind, SameKind, UnitKind};
+    use crate::{Ratio, SingleUnit};
+
+    pub trait UnitOf {
+        type Kind;
+    }
+
+    impl<Kind: UnitKind> UnitOf for SingleUnit<Kind> {
+        type Kind = Kind;
+    }
+
+    pub fn kind_of<Kind: UnitKind + Default>(_: &SingleUnit<Kind>) -> Kind {
+        Kind::default()
+    }
+
+    pub fn dimensionless() -> DimensionlessKind {
+        DimensionlessKind::default()
+    }
+
+    pub fn assert_kind<Unit: UnitOf>(_: impl SameKind<Unit::Kind>) {}
+
+    pub const fn exact_mul(left: Option<Ratio>, right: Option<Ratio>) -> Option<Ratio> {
+        match (left, right) {
+            (Some(left), Some(right)) => left.checked_mul(right),
+            _ => None,
+        }
+    }
+
+    pub const fn exact_div(left: Option<Ratio>, right: Option<Ratio>) -> Option<Ratio> {
+        match (left, right) {
+            (Some(left), Some(right)) => left.checked_div(right),
+            _ => None,
+        }
+    }
+
+    pub const fn exact_powi(base: Option<Ratio>, power: i32) -> Option<Ratio> {
+        match base {
+            Some(base) => base.checked_powi(power),
+            None => None,
+        }
+    }
+}
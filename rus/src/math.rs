@@ -0,0 +1,92 @@
+//! Floating point functions which are only inherent methods of `f32` and `f64` with `std`.
+//! Without it they come from `libm`.
+//!
+//! Integer powers are computed here in both cases, since the precision of the inherent `powi` is
+//! unspecified, and results should not depend on whether `std` is enabled.
+
+pub(crate) use imp::*;
+
+pub(crate) fn powi(x: f32, power: i32) -> f32 {
+    powi_f64(x.into(), power) as f32
+}
+
+/// Raise to an integer power by repeated squaring.
+pub(crate) fn powi_f64(x: f64, power: i32) -> f64 {
+    let mut result = 1.;
+    let mut base = x;
+    let mut exponent = power.unsigned_abs();
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exponent /= 2;
+    }
+    if power < 0 {
+        1. / result
+    } else {
+        result
+    }
+}
+
+#[cfg(feature = "std")]
+mod imp {
+    pub(crate) fn sqrt(x: f32) -> f32 {
+        x.sqrt()
+    }
+
+    pub(crate) fn exp(x: f32) -> f32 {
+        x.exp()
+    }
+
+    pub(crate) fn ln(x: f32) -> f32 {
+        x.ln()
+    }
+
+    pub(crate) fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+
+    pub(crate) fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+
+    pub(crate) fn hypot(x: f32, y: f32) -> f32 {
+        x.hypot(y)
+    }
+
+    pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub(crate) fn sqrt(x: f32) -> f32 {
+        libm::sqrtf(x)
+    }
+
+    pub(crate) fn exp(x: f32) -> f32 {
+        libm::expf(x)
+    }
+
+    pub(crate) fn ln(x: f32) -> f32 {
+        libm::logf(x)
+    }
+
+    pub(crate) fn sin(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+
+    pub(crate) fn cos(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+
+    pub(crate) fn hypot(x: f32, y: f32) -> f32 {
+        libm::hypotf(x, y)
+    }
+
+    pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+        libm::atan2f(y, x)
+    }
+}
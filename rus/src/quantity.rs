@@ -1,26 +1,50 @@
+use crate::__private::{exact_div, exact_mul};
+use crate::scalar::Scalar;
+use crate::unit::format::UnitFormat;
 use crate::unit::kind::UnitKind;
 use crate::unit::{CompositeUnit, SingleUnit};
-use std::fmt::Display;
-use std::ops::{Div, Mul};
-use typenum::{Prod, Quot};
+use core::fmt::{Display, LowerExp, UpperExp};
+use core::ops::{Add, Div, Mul, Sub};
+use typenum::{Integer, Pow, Prod, Quot};
 
-/// A single quantity containing a scalar (f32) and a unit.
+/// A single quantity containing a scalar and a unit.
 ///
 /// For example, 13 meters is a single quantity.
 /// This is in contrast with the (yet unimplemented) ArrayQuantity which contains many scalars
-/// (f32) and a single associated unit.
-#[derive(Debug, Clone)]
-pub struct SingleQuantity<Kind: UnitKind> {
-    unit: CompositeUnit<Kind>,
-    scalar: f32,
+/// and a single associated unit.
+///
+/// The scalar is an `f32` unless another [`Scalar`] is chosen, such as an
+/// [`Uncertain`](crate::scalar::Uncertain) value. A quantity is `Copy` when its scalar is.
+#[derive(Debug)]
+pub struct SingleQuantity<Kind: UnitKind, S = f32> {
+    pub(crate) unit: CompositeUnit<Kind>,
+    pub(crate) scalar: S,
+}
+
+impl<Kind: UnitKind, S: Clone> Clone for SingleQuantity<Kind, S> {
+    fn clone(&self) -> Self {
+        Self {
+            unit: self.unit,
+            scalar: self.scalar.clone(),
+        }
+    }
 }
 
-impl<Kind: UnitKind> SingleQuantity<Kind> {
+impl<Kind: UnitKind, S: Copy> Copy for SingleQuantity<Kind, S> {}
+
+impl<Kind: UnitKind, S> SingleQuantity<Kind, S> {
     /// Create a new quantity from a unit and a scalar.
-    pub fn new(unit: CompositeUnit<Kind>, scalar: f32) -> Self {
+    pub const fn new(unit: CompositeUnit<Kind>, scalar: S) -> Self {
         Self { unit, scalar }
     }
 
+    /// The scalar of this quantity, in its current unit.
+    pub fn scalar(&self) -> &S {
+        &self.scalar
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar> SingleQuantity<Kind, S> {
     /// Convert a quantity from one unit to another.
     /// ```
     /// use rus::unit_defs as u;
@@ -32,9 +56,13 @@ impl<Kind: UnitKind> SingleQuantity<Kind> {
         let unit = unit.into();
         let source_scale = self.unit.scale_factor();
         let target_scale = unit.scale_factor();
+        let exact = self.unit.exact_factor_to(&unit);
         Self {
             unit,
-            scalar: self.scalar * source_scale / target_scale,
+            scalar: self
+                .scalar
+                .clone()
+                .scale_exact(source_scale / target_scale, exact),
         }
     }
 
@@ -43,78 +71,237 @@ impl<Kind: UnitKind> SingleQuantity<Kind> {
     /// use rus::unit_defs as u;
     /// let distance = 12. * u::cm;
     /// let time = 2. * u::s;
-    /// let velocity1 = distance.clone() / time.clone();
+    /// let velocity1 = distance / time;
     /// let velocity2 = distance.to(u::m) / time;
     /// assert_eq!(velocity1, velocity2);
     /// assert!(!velocity1.unit_eq(velocity2));
     /// ```
-    pub fn unit_eq(&self, other: Self) -> bool {
+    pub fn unit_eq(&self, other: Self) -> bool
+    where
+        S: PartialEq,
+    {
         self.scalar == other.scalar && self.unit == other.unit
     }
+
+    /// Raise this quantity to a power, given as a [`typenum`] integer so that the kind of the
+    /// result is known.
+    /// ```
+    /// use rus::typenum::{N1, P2};
+    /// use rus::unit_defs as u;
+    /// let side = 3. * u::cm;
+    /// assert_eq!(side.powi(P2::new()).to_string(), "9 cm^2");
+    /// assert_eq!(format!("{:.1}", side.powi(N1::new())), "0.3 cm^-1");
+    /// ```
+    ///
+    /// # Panics
+    /// For integer scalars, panics if the result overflows, or if a quantity of zero is raised to
+    /// a negative power.
+    pub fn powi<N: Integer>(self, _: N) -> SingleQuantity<<Kind as Pow<N>>::Output, S>
+    where
+        Kind: Pow<N>,
+        <Kind as Pow<N>>::Output: UnitKind,
+    {
+        let units = self
+            .unit
+            .component_units
+            .iter()
+            .map(|(unit, unit_power)| (unit, unit_power * N::I8))
+            .collect();
+        SingleQuantity::new(CompositeUnit::new(units), self.scalar.powi(N::I32))
+    }
+}
+
+/// The result has the unit of the left hand side.
+/// ```
+/// use rus::unit_defs as u;
+/// let length = 1. * u::m + 50. * u::cm;
+/// assert_eq!(length.to_string(), "1.5 m");
+/// ```
+///
+/// # Panics
+/// For integer scalars, panics if the sum overflows, in release builds too.
+/// [`SingleQuantity::checked_add`] returns `None` instead.
+impl<Kind: UnitKind, S: Scalar> Add for SingleQuantity<Kind, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let rhs = rhs.to(self.unit);
+        Self::new(self.unit, self.scalar.strict_add(rhs.scalar))
+    }
 }
 
-impl<Kind1: UnitKind, Kind2: UnitKind> Mul<SingleQuantity<Kind2>> for SingleQuantity<Kind1>
+/// The result has the unit of the left hand side.
+///
+/// # Panics
+/// For integer scalars, panics if the difference overflows, in release builds too.
+/// [`SingleQuantity::checked_sub`] returns `None` instead.
+impl<Kind: UnitKind, S: Scalar> Sub for SingleQuantity<Kind, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let rhs = rhs.to(self.unit);
+        Self::new(self.unit, self.scalar.strict_sub(rhs.scalar))
+    }
+}
+
+/// If the unit of the product would be made of too many distinct units, it is written in SI base
+/// units instead.
+///
+/// # Panics
+/// For integer scalars, panics if the product overflows, in release builds too.
+/// [`SingleQuantity::checked_mul`] returns `None` instead.
+impl<Kind1: UnitKind, Kind2: UnitKind, S: Scalar> Mul<SingleQuantity<Kind2, S>>
+    for SingleQuantity<Kind1, S>
 where
     Kind1: Mul<Kind2>,
     Prod<Kind1, Kind2>: UnitKind,
 {
-    type Output = SingleQuantity<Prod<Kind1, Kind2>>;
+    type Output = SingleQuantity<Prod<Kind1, Kind2>, S>;
 
-    fn mul(self, rhs: SingleQuantity<Kind2>) -> Self::Output {
-        Self::Output::new(self.unit * rhs.unit, self.scalar * rhs.scalar)
+    fn mul(self, rhs: SingleQuantity<Kind2, S>) -> Self::Output {
+        let scalar = self.scalar.strict_mul(rhs.scalar);
+        match self.unit.checked_mul(rhs.unit) {
+            Some(unit) => Self::Output::new(unit, scalar),
+            None => Self::Output::new(
+                CompositeUnit::si(),
+                scalar.scale_exact(
+                    self.unit.scale_factor() * rhs.unit.scale_factor(),
+                    exact_mul(
+                        self.unit.exact_scale_factor(),
+                        rhs.unit.exact_scale_factor(),
+                    ),
+                ),
+            ),
+        }
     }
 }
 
-impl<Kind1: UnitKind, Kind2: UnitKind> Mul<SingleUnit<Kind2>> for SingleQuantity<Kind1>
+impl<Kind1: UnitKind, Kind2: UnitKind, S: Scalar> Mul<SingleUnit<Kind2>>
+    for SingleQuantity<Kind1, S>
 where
     Kind1: Mul<Kind2>,
     Prod<Kind1, Kind2>: UnitKind,
 {
-    type Output = SingleQuantity<Prod<Kind1, Kind2>>;
+    type Output = SingleQuantity<Prod<Kind1, Kind2>, S>;
 
     fn mul(self, rhs: SingleUnit<Kind2>) -> Self::Output {
-        Self::Output {
-            unit: self.unit * rhs,
-            scalar: self.scalar,
+        let rhs = CompositeUnit::from(rhs);
+        match self.unit.checked_mul(rhs) {
+            Some(unit) => Self::Output::new(unit, self.scalar),
+            None => Self::Output::new(
+                CompositeUnit::si(),
+                self.scalar.scale_exact(
+                    self.unit.scale_factor() * rhs.scale_factor(),
+                    exact_mul(self.unit.exact_scale_factor(), rhs.exact_scale_factor()),
+                ),
+            ),
         }
     }
 }
 
-impl<Kind: UnitKind> Display for SingleQuantity<Kind> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} {}", self.scalar, self.unit)
+/// The unit is written as described in the [`Display`] implementation of [`CompositeUnit`],
+/// including the alternate flag (`{:#}`) for superscript powers. Other formatting options, such as
+/// precision and width, apply to the scalar.
+/// ```
+/// use rus::unit_defs as u;
+/// let distance = 1234.5678 * u::km;
+/// assert_eq!(format!("{distance:.2}"), "1234.57 km");
+/// assert_eq!(format!("{distance:>8.1}"), "  1234.6 km");
+/// assert_eq!(format!("{distance:.3e}"), "1.235e3 km");
+/// ```
+impl<Kind: UnitKind, S: Scalar + Display> Display for SingleQuantity<Kind, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.format(UnitFormat::display(f.alternate())), f)
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar + LowerExp> LowerExp for SingleQuantity<Kind, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        LowerExp::fmt(&self.format(UnitFormat::display(f.alternate())), f)
     }
 }
 
-impl<Kind1: UnitKind, Kind2: UnitKind> Div<SingleQuantity<Kind2>> for SingleQuantity<Kind1>
+impl<Kind: UnitKind, S: Scalar + UpperExp> UpperExp for SingleQuantity<Kind, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        UpperExp::fmt(&self.format(UnitFormat::display(f.alternate())), f)
+    }
+}
+
+/// Scale a quantity by a number, keeping its unit.
+/// ```
+/// use rus::constants::c;
+/// assert_eq!(format!("{:.3e}", 0.5 * c), "1.499e8 m s^-1");
+/// ```
+impl<Kind: UnitKind> Mul<SingleQuantity<Kind>> for f32 {
+    type Output = SingleQuantity<Kind>;
+
+    fn mul(self, rhs: SingleQuantity<Kind>) -> Self::Output {
+        SingleQuantity::new(rhs.unit, self * rhs.scalar)
+    }
+}
+
+/// If the unit of the quotient would be made of too many distinct units, it is written in SI base
+/// units instead.
+///
+/// # Panics
+/// For integer scalars, panics if the divisor is zero or the quotient overflows, in release builds
+/// too. [`SingleQuantity::checked_div`] returns `None` instead.
+impl<Kind1: UnitKind, Kind2: UnitKind, S: Scalar> Div<SingleQuantity<Kind2, S>>
+    for SingleQuantity<Kind1, S>
 where
     Kind1: Div<Kind2>,
     Quot<Kind1, Kind2>: UnitKind,
 {
-    type Output = SingleQuantity<Quot<Kind1, Kind2>>;
+    type Output = SingleQuantity<Quot<Kind1, Kind2>, S>;
 
-    fn div(self, rhs: SingleQuantity<Kind2>) -> Self::Output {
-        Self::Output::new(self.unit / rhs.unit, self.scalar / rhs.scalar)
+    fn div(self, rhs: SingleQuantity<Kind2, S>) -> Self::Output {
+        let scalar = self.scalar.strict_div(rhs.scalar);
+        match self.unit.checked_div(rhs.unit) {
+            Some(unit) => Self::Output::new(unit, scalar),
+            None => Self::Output::new(
+                CompositeUnit::si(),
+                scalar.scale_exact(
+                    self.unit.scale_factor() / rhs.unit.scale_factor(),
+                    exact_div(
+                        self.unit.exact_scale_factor(),
+                        rhs.unit.exact_scale_factor(),
+                    ),
+                ),
+            ),
+        }
     }
 }
 
-impl<Kind1: UnitKind, Kind2: UnitKind> Div<SingleUnit<Kind2>> for SingleQuantity<Kind1>
+impl<Kind1: UnitKind, Kind2: UnitKind, S: Scalar> Div<SingleUnit<Kind2>>
+    for SingleQuantity<Kind1, S>
 where
     Kind1: Div<Kind2>,
     Quot<Kind1, Kind2>: UnitKind,
 {
-    type Output = SingleQuantity<Quot<Kind1, Kind2>>;
+    type Output = SingleQuantity<Quot<Kind1, Kind2>, S>;
 
     fn div(self, rhs: SingleUnit<Kind2>) -> Self::Output {
-        Self::Output {
-            unit: self.unit / rhs,
-            scalar: self.scalar,
+        let rhs = CompositeUnit::from(rhs);
+        match self.unit.checked_div(rhs) {
+            Some(unit) => Self::Output::new(unit, self.scalar),
+            None => Self::Output::new(
+                CompositeUnit::si(),
+                self.scalar.scale_exact(
+                    self.unit.scale_factor() / rhs.scale_factor(),
+                    exact_div(self.unit.exact_scale_factor(), rhs.exact_scale_factor()),
+                ),
+            ),
         }
     }
 }
 
-impl<Kind: UnitKind + PartialEq> PartialEq for SingleQuantity<Kind> {
+impl<Kind: UnitKind + PartialEq, S: Scalar + PartialEq> PartialEq for SingleQuantity<Kind, S> {
     fn eq(&self, other: &Self) -> bool {
-        self.scalar * self.unit.scale_factor() == other.scalar * other.unit.scale_factor()
+        self.scalar.scaled_eq(
+            self.unit.scale_factor(),
+            &other.scalar,
+            other.unit.scale_factor(),
+            self.unit.exact_factor_to(&other.unit),
+        )
     }
 }
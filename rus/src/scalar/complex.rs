@@ -0,0 +1,232 @@
+use super::Scalar;
+use crate::kind::{AngleKind, UnitKind};
+use crate::math;
+use crate::quantity::SingleQuantity;
+use crate::unit_defs::rad;
+use crate::SingleUnit;
+use core::fmt::{Display, Formatter, LowerExp, Result, UpperExp};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A complex number `re + im j`, for quantities such as impedances and phasors.
+///
+/// The imaginary unit is written `j`, as is usual in electrical engineering.
+/// ```
+/// use rus::scalar::Complex;
+/// use rus::unit_defs as u;
+/// let impedance = Complex::new(30., 40.) * u::ohm;
+/// let current = Complex::new(2., 0.) * u::A;
+/// let voltage = impedance * current;
+/// assert_eq!(voltage.to(u::W / u::A).to_string(), "60 + 80j W A^-1");
+/// assert_eq!(impedance.abs().to_string(), "50 Ω");
+/// assert_eq!(format!("{:.1}", impedance.arg().to(u::deg)), "53.1 °");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    /// The real part.
+    pub re: f32,
+    /// The imaginary part.
+    pub im: f32,
+}
+
+impl Complex {
+    /// The imaginary unit.
+    pub const J: Self = Self::new(0., 1.);
+
+    /// Create a complex number from its real and imaginary parts.
+    pub const fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    /// Create a complex number from its absolute value and its argument in radians.
+    pub fn from_polar(abs: f32, arg: f32) -> Self {
+        Self::new(abs * math::cos(arg), abs * math::sin(arg))
+    }
+
+    /// The absolute value, or magnitude.
+    pub fn abs(&self) -> f32 {
+        math::hypot(self.re, self.im)
+    }
+
+    /// The argument, or phase, in radians between -π and π.
+    pub fn arg(&self) -> f32 {
+        math::atan2(self.im, self.re)
+    }
+
+    /// The complex conjugate.
+    pub const fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// The sign written between the real and imaginary parts.
+    fn separator(&self) -> &'static str {
+        if self.im.is_sign_negative() {
+            " - "
+        } else {
+            " + "
+        }
+    }
+}
+
+impl From<f32> for Complex {
+    fn from(re: f32) -> Self {
+        Self::new(re, 0.)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let norm = rhs.re * rhs.re + rhs.im * rhs.im;
+        let numerator = self * rhs.conj();
+        Self::new(numerator.re / norm, numerator.im / norm)
+    }
+}
+
+impl Scalar for Complex {
+    fn scale(self, factor: f32) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+
+    fn powi(self, power: i32) -> Self {
+        // Repeated squaring keeps powers of real and imaginary numbers exact.
+        let mut result = Self::new(1., 0.);
+        let mut base = self;
+        let mut exponent = power.unsigned_abs();
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent /= 2;
+        }
+        if power < 0 {
+            Self::new(1., 0.) / result
+        } else {
+            result
+        }
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.abs()
+    }
+}
+
+/// Written as `re + im j`, with formatting options such as precision applying to both parts.
+impl Display for Complex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.re, f)?;
+        f.write_str(self.separator())?;
+        Display::fmt(&self.im.abs(), f)?;
+        f.write_str("j")
+    }
+}
+
+impl LowerExp for Complex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerExp::fmt(&self.re, f)?;
+        f.write_str(self.separator())?;
+        LowerExp::fmt(&self.im.abs(), f)?;
+        f.write_str("j")
+    }
+}
+
+impl UpperExp for Complex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperExp::fmt(&self.re, f)?;
+        f.write_str(self.separator())?;
+        UpperExp::fmt(&self.im.abs(), f)?;
+        f.write_str("j")
+    }
+}
+
+impl<Kind: UnitKind> Mul<SingleUnit<Kind>> for Complex {
+    type Output = SingleQuantity<Kind, Complex>;
+
+    fn mul(self, rhs: SingleUnit<Kind>) -> Self::Output {
+        SingleQuantity::new(rhs.into(), self)
+    }
+}
+
+impl<Kind: UnitKind> SingleQuantity<Kind, Complex> {
+    /// Create a phasor from its magnitude and phase. The result has the unit of `abs`.
+    /// ```
+    /// use rus::scalar::Complex;
+    /// use rus::unit_defs as u;
+    /// use rus::SingleQuantity;
+    /// let current = SingleQuantity::<_, Complex>::from_polar(2. * u::A, -60. * u::deg);
+    /// assert_eq!(format!("{current:.2}"), "1.00 - 1.73j A");
+    /// ```
+    pub fn from_polar(abs: SingleQuantity<Kind>, arg: SingleQuantity<AngleKind>) -> Self {
+        let arg = arg.to(rad);
+        Self::new(abs.unit, Complex::from_polar(abs.scalar, arg.scalar))
+    }
+
+    /// The real part, such as the resistance of an impedance.
+    pub fn re(&self) -> SingleQuantity<Kind> {
+        SingleQuantity::new(self.unit, self.scalar.re)
+    }
+
+    /// The imaginary part, such as the reactance of an impedance.
+    pub fn im(&self) -> SingleQuantity<Kind> {
+        SingleQuantity::new(self.unit, self.scalar.im)
+    }
+
+    /// The absolute value, or magnitude, which has the same kind as the quantity.
+    pub fn abs(&self) -> SingleQuantity<Kind> {
+        SingleQuantity::new(self.unit, self.scalar.abs())
+    }
+
+    /// The argument, or phase, in radians.
+    pub fn arg(&self) -> SingleQuantity<AngleKind> {
+        SingleQuantity::new(rad.into(), self.scalar.arg())
+    }
+
+    /// The complex conjugate.
+    /// ```
+    /// use rus::scalar::Complex;
+    /// use rus::unit_defs as u;
+    /// let voltage = Complex::new(0., 10.) * u::W / u::A;
+    /// let current = Complex::new(2., 2.) * u::A;
+    /// let power = voltage * current.conj();
+    /// assert_eq!(power.to(u::W).to_string(), "20 + 20j W");
+    /// ```
+    pub fn conj(self) -> Self {
+        Self::new(self.unit, self.scalar.conj())
+    }
+}
@@ -0,0 +1,224 @@
+use super::Scalar;
+use crate::kind::UnitKind;
+use crate::math;
+use crate::quantity::SingleQuantity;
+use crate::SingleUnit;
+use core::fmt::{Display, Formatter, LowerExp, Result, UpperExp};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use typenum::Quot;
+
+/// A dual number `value + derivative ε`, where `ε² = 0`, for forward-mode automatic
+/// differentiation.
+///
+/// Create the variable to differentiate with respect to using [`Dual::variable`], and every
+/// other input using [`Dual::constant`]. Each result then carries its derivative with respect to
+/// the variable, which [`SingleQuantity::derivative`] returns with the right unit.
+/// ```
+/// use rus::scalar::Dual;
+/// use rus::unit_defs as u;
+/// let t = Dual::variable(2.) * u::s;
+/// let v0 = Dual::constant(3.) * u::m / u::s;
+/// let half_a = Dual::constant(-2.) * u::m / u::s / u::s;
+/// let position = v0 * t + half_a * t * t;
+/// assert_eq!(position.value().to_string(), "-2 m");
+/// assert_eq!(position.derivative(t).to_string(), "-5 m s^-1");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual {
+    /// The value of the function.
+    pub value: f32,
+    /// The derivative of the function with respect to the variable.
+    pub derivative: f32,
+}
+
+impl Dual {
+    /// Create a dual number from a value and a derivative.
+    pub const fn new(value: f32, derivative: f32) -> Self {
+        Self { value, derivative }
+    }
+
+    /// Create the variable to differentiate with respect to, whose derivative is one.
+    pub const fn variable(value: f32) -> Self {
+        Self::new(value, 1.)
+    }
+
+    /// Create a value which does not depend on the variable.
+    pub const fn constant(value: f32) -> Self {
+        Self::new(value, 0.)
+    }
+
+    /// The square root.
+    pub fn sqrt(self) -> Self {
+        let value = math::sqrt(self.value);
+        Self::new(value, self.derivative / (2. * value))
+    }
+
+    /// The exponential function.
+    pub fn exp(self) -> Self {
+        let value = math::exp(self.value);
+        Self::new(value, self.derivative * value)
+    }
+
+    /// The natural logarithm.
+    pub fn ln(self) -> Self {
+        Self::new(math::ln(self.value), self.derivative / self.value)
+    }
+
+    /// The sine, of a value in radians.
+    pub fn sin(self) -> Self {
+        Self::new(
+            math::sin(self.value),
+            self.derivative * math::cos(self.value),
+        )
+    }
+
+    /// The cosine, of a value in radians.
+    pub fn cos(self) -> Self {
+        Self::new(
+            math::cos(self.value),
+            -self.derivative * math::sin(self.value),
+        )
+    }
+}
+
+impl From<f32> for Dual {
+    fn from(value: f32) -> Self {
+        Self::constant(value)
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value * rhs.value,
+            self.derivative * rhs.value + self.value * rhs.derivative,
+        )
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let value = self.value / rhs.value;
+        Self::new(
+            value,
+            (self.derivative - value * rhs.derivative) / rhs.value,
+        )
+    }
+}
+
+impl Scalar for Dual {
+    fn scale(self, factor: f32) -> Self {
+        Self::new(self.value * factor, self.derivative * factor)
+    }
+
+    fn powi(self, power: i32) -> Self {
+        let derivative = power as f32 * math::powi(self.value, power - 1);
+        Self::new(math::powi(self.value, power), derivative * self.derivative)
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.value
+    }
+}
+
+/// Written as `value + derivative ε`, with formatting options such as precision applying to both
+/// parts.
+impl Display for Dual {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.value, f)?;
+        f.write_str(" + ")?;
+        Display::fmt(&self.derivative, f)?;
+        f.write_str("ε")
+    }
+}
+
+impl LowerExp for Dual {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerExp::fmt(&self.value, f)?;
+        f.write_str(" + ")?;
+        LowerExp::fmt(&self.derivative, f)?;
+        f.write_str("ε")
+    }
+}
+
+impl UpperExp for Dual {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperExp::fmt(&self.value, f)?;
+        f.write_str(" + ")?;
+        UpperExp::fmt(&self.derivative, f)?;
+        f.write_str("ε")
+    }
+}
+
+impl<Kind: UnitKind> Mul<SingleUnit<Kind>> for Dual {
+    type Output = SingleQuantity<Kind, Dual>;
+
+    fn mul(self, rhs: SingleUnit<Kind>) -> Self::Output {
+        SingleQuantity::new(rhs.into(), self)
+    }
+}
+
+impl<Kind: UnitKind> SingleQuantity<Kind, Dual> {
+    /// The value of the quantity, without its derivative.
+    pub fn value(&self) -> SingleQuantity<Kind> {
+        SingleQuantity::new(self.unit, self.scalar.value)
+    }
+
+    /// The derivative of the quantity with respect to `variable`, which is usually the quantity
+    /// created with [`Dual::variable`]. The unit of `variable` is taken into account, and the kind
+    /// of the result is checked at compile time, so the derivative of a length with respect to a
+    /// time is a velocity.
+    /// ```
+    /// use rus::kind::VelocityKind;
+    /// use rus::scalar::Dual;
+    /// use rus::unit_defs as u;
+    /// use rus::SingleQuantity;
+    /// let t = Dual::variable(30.) * u::min;
+    /// let distance = (Dual::constant(12.) * u::km / u::h) * t;
+    /// let speed: SingleQuantity<VelocityKind> = distance.derivative(t);
+    /// assert_eq!(format!("{:.1}", speed.to(u::km / u::h)), "12.0 km h^-1");
+    /// ```
+    ///
+    /// Any quantity computed from the same variable may be given instead, in which case the
+    /// result is found by the chain rule. It is not finite where `variable` does not change.
+    pub fn derivative<Wrt: UnitKind>(
+        &self,
+        variable: SingleQuantity<Wrt, Dual>,
+    ) -> SingleQuantity<Quot<Kind, Wrt>>
+    where
+        Kind: Div<Wrt>,
+        Quot<Kind, Wrt>: UnitKind,
+    {
+        let numerator = SingleQuantity::new(self.unit, self.scalar.derivative);
+        let denominator = SingleQuantity::new(variable.unit, variable.scalar.derivative);
+        numerator / denominator
+    }
+}
@@ -0,0 +1,322 @@
+use super::Scalar;
+use crate::__private::{exact_div, exact_mul};
+use crate::kind::UnitKind;
+use crate::quantity::SingleQuantity;
+use crate::{CompositeUnit, Ratio, SingleUnit};
+use core::ops::{Div, Mul};
+use typenum::{Prod, Quot};
+
+/// How to round the result of converting an integer quantity to another unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round to the nearest integer, with halfway cases rounded away from zero.
+    #[default]
+    Nearest,
+    /// Round towards negative infinity.
+    Down,
+    /// Round towards positive infinity.
+    Up,
+    /// Round towards zero, discarding the fractional part.
+    TowardZero,
+}
+
+/// Implemented by the primitive integers, which can be used as the scalar of a quantity holding
+/// raw counts of its unit.
+///
+/// Conversions between units are done in integer arithmetic. The ratio between two units is used
+/// exactly when both have an exact [`Ratio`] as their scale, as units defined from decimal numbers
+/// do, and otherwise the exact value of its `f32` is used.
+///
+/// Conversions through [`Scalar::scale`], such as [`SingleQuantity::to`], round to nearest and
+/// panic on overflow. [`SingleQuantity::checked_to`] takes the rounding explicitly and returns
+/// `None` on overflow instead. Likewise, arithmetic on integer quantities panics on overflow, in
+/// release builds too, while [`SingleQuantity::checked_add`] and its siblings return `None`.
+/// Comparing quantities never rounds, so `1 mV` and `1 μV` are not
+/// equal.
+/// ```
+/// use rus::scalar::Rounding;
+/// use rus::unit_defs as u;
+/// let reading = 1_234_567_i32 * u::uV;
+/// assert_eq!(reading.to(u::mV).to_string(), "1235 mV");
+/// let volts = reading.checked_to(u::V, Rounding::Down).unwrap();
+/// assert_eq!(volts.to_string(), "1 V");
+/// assert!((30_000_i16 * u::mV).checked_to(u::uV, Rounding::Nearest).is_none());
+/// ```
+pub trait IntegerScalar: Scalar + Copy + Into<i128> + TryFrom<i128> {
+    /// Multiply by a real factor, rounding as given. Returns `None` if the result does not fit.
+    fn checked_scale(self, factor: f32, rounding: Rounding) -> Option<Self> {
+        self.checked_scale_exact(factor, None, rounding)
+    }
+
+    /// Multiply by a real factor, which is used exactly if `exact` is given and fits in integer
+    /// arithmetic, rounding as given. Returns `None` if the result does not fit.
+    fn checked_scale_exact(
+        self,
+        factor: f32,
+        exact: Option<Ratio>,
+        rounding: Rounding,
+    ) -> Option<Self> {
+        let (numerator, denominator) = match exact.and_then(Ratio::fraction) {
+            Some(fraction) => fraction,
+            None => ratio(factor)?,
+        };
+        let product = self.into().checked_mul(numerator)?;
+        Self::try_from(divide(product, denominator, rounding)).ok()
+    }
+}
+
+/// The largest power of two by which a product is divided. Every product of an integer scalar and
+/// an `f32` mantissa is below half of `2^MAX_SHIFT`, so it rounds as it would for any larger power.
+const MAX_SHIFT: i32 = 126;
+
+/// Express the exact value of a factor as a ratio of integers, with a positive denominator.
+fn ratio(factor: f32) -> Option<(i128, i128)> {
+    if !factor.is_finite() {
+        return None;
+    }
+    if factor == 0. {
+        return Some((0, 1));
+    }
+    let sign = if factor < 0. { -1 } else { 1 };
+    // The value of the f32 is a mantissa times a power of two.
+    let bits = factor.abs().to_bits();
+    let exponent = (bits >> 23) as i32;
+    let fraction = (bits & 0x7f_ffff) as i128;
+    let (mantissa, power) = if exponent == 0 {
+        (fraction, -149)
+    } else {
+        (fraction | 0x80_0000, exponent - 150)
+    };
+    if power >= 0 {
+        let numerator = mantissa.checked_mul(1_i128.checked_shl(power as u32)?)?;
+        Some((sign * numerator, 1))
+    } else {
+        Some((sign * mantissa, 1 << (-power).min(MAX_SHIFT)))
+    }
+}
+
+/// Whether `value * numerator == other * denominator`, without overflowing.
+fn exact_eq(value: i128, other: i128, (numerator, denominator): (i128, i128)) -> bool {
+    let divisor = gcd(numerator.unsigned_abs(), denominator.unsigned_abs()) as i128;
+    let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+    // With the ratio in lowest terms, the denominator must divide the value.
+    value % denominator == 0 && (value / denominator).checked_mul(numerator) == Some(other)
+}
+
+/// The greatest common divisor of two integers, which are not both zero.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Divide by a positive denominator, rounding as given.
+fn divide(numerator: i128, denominator: i128, rounding: Rounding) -> i128 {
+    let floor = numerator.div_euclid(denominator);
+    let remainder = numerator.rem_euclid(denominator);
+    if remainder == 0 {
+        return floor;
+    }
+    match rounding {
+        Rounding::Down => floor,
+        Rounding::Up => floor + 1,
+        Rounding::TowardZero if numerator < 0 => floor + 1,
+        Rounding::TowardZero => floor,
+        Rounding::Nearest => {
+            let twice = 2 * remainder;
+            if twice > denominator || (twice == denominator && numerator > 0) {
+                floor + 1
+            } else {
+                floor
+            }
+        }
+    }
+}
+
+macro_rules! impl_integer_scalar {
+    ($($integer:ty),*) => {$(
+        impl IntegerScalar for $integer {}
+
+        impl Scalar for $integer {
+            fn scale(self, factor: f32) -> Self {
+                self.scale_exact(factor, None)
+            }
+
+            fn scale_exact(self, factor: f32, exact: Option<Ratio>) -> Self {
+                self.checked_scale_exact(factor, exact, Rounding::Nearest)
+                    .expect("integer quantity overflowed when converting units")
+            }
+
+            /// Compares the raw counts exactly, converting by the exact ratio between the units if
+            /// it is known, and otherwise by the exact value of the `f32` ratio.
+            fn scaled_eq(
+                &self,
+                scale: f32,
+                other: &Self,
+                other_scale: f32,
+                exact: Option<Ratio>,
+            ) -> bool {
+                match exact.and_then(Ratio::fraction).or_else(|| ratio(scale / other_scale)) {
+                    Some(fraction) => exact_eq((*self).into(), (*other).into(), fraction),
+                    None => false,
+                }
+            }
+
+            fn strict_add(self, rhs: Self) -> Self {
+                self.checked_add(rhs)
+                    .expect("integer quantity overflowed when adding")
+            }
+
+            fn strict_sub(self, rhs: Self) -> Self {
+                self.checked_sub(rhs)
+                    .expect("integer quantity overflowed when subtracting")
+            }
+
+            fn strict_mul(self, rhs: Self) -> Self {
+                self.checked_mul(rhs)
+                    .expect("integer quantity overflowed when multiplying")
+            }
+
+            fn strict_div(self, rhs: Self) -> Self {
+                self.checked_div(rhs)
+                    .expect("integer quantity divided by zero or overflowed when dividing")
+            }
+
+            /// Negative powers use integer division, so they truncate towards zero.
+            ///
+            /// # Panics
+            /// Panics if the result overflows, or if zero is raised to a negative power.
+            fn powi(self, power: i32) -> Self {
+                let result = self
+                    .checked_pow(power.unsigned_abs())
+                    .expect("integer quantity overflowed when raised to a power");
+                if power < 0 {
+                    <$integer>::checked_div(1, result)
+                        .expect("integer quantity of zero raised to a negative power")
+                } else {
+                    result
+                }
+            }
+
+            fn to_f32(&self) -> f32 {
+                *self as f32
+            }
+        }
+
+        impl<Kind: UnitKind> Mul<SingleUnit<Kind>> for $integer {
+            type Output = SingleQuantity<Kind, $integer>;
+
+            fn mul(self, rhs: SingleUnit<Kind>) -> Self::Output {
+                SingleQuantity::new(rhs.into(), self)
+            }
+        }
+    )*};
+}
+
+impl_integer_scalar!(i8, i16, i32, i64);
+
+impl<Kind: UnitKind, S: IntegerScalar> SingleQuantity<Kind, S> {
+    /// Convert a quantity from one unit to another, rounding as given. Returns `None` if the
+    /// result does not fit in the integer type.
+    /// ```
+    /// use rus::scalar::Rounding;
+    /// use rus::unit_defs as u;
+    /// let time = -1500_i32 * u::ms;
+    /// let round = |rounding| time.checked_to(u::s, rounding).unwrap().to_string();
+    /// assert_eq!(round(Rounding::Nearest), "-2 s");
+    /// assert_eq!(round(Rounding::Down), "-2 s");
+    /// assert_eq!(round(Rounding::Up), "-1 s");
+    /// assert_eq!(round(Rounding::TowardZero), "-1 s");
+    /// ```
+    pub fn checked_to(
+        &self,
+        unit: impl Into<CompositeUnit<Kind>>,
+        rounding: Rounding,
+    ) -> Option<Self> {
+        let unit = unit.into();
+        let factor = self.unit.scale_factor() / unit.scale_factor();
+        let exact = self.unit.exact_factor_to(&unit);
+        let scalar = self.scalar.checked_scale_exact(factor, exact, rounding)?;
+        Some(Self::new(unit, scalar))
+    }
+
+    /// Add a quantity, converted to the unit of this one, rounding to nearest as `+` does.
+    /// Returns `None` if the conversion or the sum overflows.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let reading = 2_000_000_000_i32 * u::mV;
+    /// assert!(reading.checked_add(reading).is_none());
+    /// assert_eq!(reading.checked_add(1_i32 * u::V).unwrap().to_string(), "2000001000 mV");
+    /// ```
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let rhs = rhs.checked_to(self.unit, Rounding::Nearest)?;
+        let sum = S::try_from(self.scalar.into() + rhs.scalar.into()).ok()?;
+        Some(Self::new(self.unit, sum))
+    }
+
+    /// Subtract a quantity, converted to the unit of this one, rounding to nearest as `-` does.
+    /// Returns `None` if the conversion or the difference overflows.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let rhs = rhs.checked_to(self.unit, Rounding::Nearest)?;
+        let difference = S::try_from(self.scalar.into() - rhs.scalar.into()).ok()?;
+        Some(Self::new(self.unit, difference))
+    }
+
+    /// Multiply by a quantity. Returns `None` if the product overflows, including when it is
+    /// converted to SI base units because its unit would be made of too many distinct units.
+    pub fn checked_mul<Kind2: UnitKind>(
+        self,
+        rhs: SingleQuantity<Kind2, S>,
+    ) -> Option<SingleQuantity<Prod<Kind, Kind2>, S>>
+    where
+        Kind: Mul<Kind2>,
+        Prod<Kind, Kind2>: UnitKind,
+    {
+        let scalar = S::try_from(self.scalar.into().checked_mul(rhs.scalar.into())?).ok()?;
+        match self.unit.checked_mul(rhs.unit) {
+            Some(unit) => Some(SingleQuantity::new(unit, scalar)),
+            None => {
+                let factor = self.unit.scale_factor() * rhs.unit.scale_factor();
+                let exact = exact_mul(
+                    self.unit.exact_scale_factor(),
+                    rhs.unit.exact_scale_factor(),
+                );
+                let scalar = scalar.checked_scale_exact(factor, exact, Rounding::Nearest)?;
+                Some(SingleQuantity::new(CompositeUnit::si(), scalar))
+            }
+        }
+    }
+
+    /// Divide by a quantity, truncating towards zero as integer division does. Returns `None` if
+    /// the divisor is zero or the quotient overflows, including when it is converted to SI base
+    /// units because its unit would be made of too many distinct units.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let distance = 7_i32 * u::m;
+    /// assert_eq!(distance.checked_div(2_i32 * u::s).unwrap().to_string(), "3 m s^-1");
+    /// assert!(distance.checked_div(0_i32 * u::s).is_none());
+    /// ```
+    pub fn checked_div<Kind2: UnitKind>(
+        self,
+        rhs: SingleQuantity<Kind2, S>,
+    ) -> Option<SingleQuantity<Quot<Kind, Kind2>, S>>
+    where
+        Kind: Div<Kind2>,
+        Quot<Kind, Kind2>: UnitKind,
+    {
+        let scalar = S::try_from(self.scalar.into().checked_div(rhs.scalar.into())?).ok()?;
+        match self.unit.checked_div(rhs.unit) {
+            Some(unit) => Some(SingleQuantity::new(unit, scalar)),
+            None => {
+                let factor = self.unit.scale_factor() / rhs.unit.scale_factor();
+                let exact = exact_div(
+                    self.unit.exact_scale_factor(),
+                    rhs.unit.exact_scale_factor(),
+                );
+                let scalar = scalar.checked_scale_exact(factor, exact, Rounding::Nearest)?;
+                Some(SingleQuantity::new(CompositeUnit::si(), scalar))
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use super::Scalar;
+use crate::kind::UnitKind;
+use crate::math;
+use crate::quantity::SingleQuantity;
+use crate::SingleUnit;
+use core::fmt::{Display, Formatter, LowerExp, Result, UpperExp};
+use core::ops::{Add, Div, Mul, Sub};
+
+/// A closed interval `[lo, hi]` which is guaranteed to contain the true value.
+///
+/// Every operation rounds its bounds outward, so the result contains every value which could
+/// come from values in the operands. Conversion factors between units are treated as accurate to
+/// within one unit in the last place of an `f32`.
+/// ```
+/// use rus::scalar::Interval;
+/// use rus::unit_defs as u;
+/// let shaft = Interval::around(10., 0.02) * u::mm;
+/// let gap = Interval::around(0.5, 0.05) * u::mm;
+/// let stack = shaft + gap + gap;
+/// assert_eq!(format!("{stack:.2}"), "[10.88, 11.12] mm");
+/// assert!(stack.contains(&(11.1 * u::mm)));
+/// assert!(!stack.contains(&(1.2 * u::cm)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    lo: f32,
+    hi: f32,
+}
+
+impl Interval {
+    /// Create the interval between two bounds, which may be given in either order.
+    pub fn new(lo: f32, hi: f32) -> Self {
+        Self {
+            lo: lo.min(hi),
+            hi: lo.max(hi),
+        }
+    }
+
+    /// Create an interval containing a single value.
+    pub const fn point(value: f32) -> Self {
+        Self {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    /// Create the interval within `tolerance` of `center`.
+    pub fn around(center: f32, tolerance: f32) -> Self {
+        let tolerance = tolerance.abs();
+        Self::rounded(center - tolerance, center + tolerance)
+    }
+
+    /// The lower bound.
+    pub const fn lo(&self) -> f32 {
+        self.lo
+    }
+
+    /// The upper bound.
+    pub const fn hi(&self) -> f32 {
+        self.hi
+    }
+
+    /// The width of the interval, rounded up.
+    pub fn width(&self) -> f32 {
+        (self.hi - self.lo).next_up()
+    }
+
+    /// The value halfway between the bounds.
+    pub fn midpoint(&self) -> f32 {
+        self.lo / 2. + self.hi / 2.
+    }
+
+    /// Whether `value` lies within the interval.
+    pub fn contains(&self, value: f32) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    /// The interval between two bounds which were each rounded to nearest, widened by the
+    /// rounding.
+    fn rounded(lo: f32, hi: f32) -> Self {
+        Self::new(lo.next_down(), hi.next_up())
+    }
+
+    /// The smallest interval containing all of `values`, each of which was rounded to nearest.
+    fn hull(values: [f32; 4]) -> Self {
+        let lo = values.into_iter().fold(f32::INFINITY, f32::min);
+        let hi = values.into_iter().fold(f32::NEG_INFINITY, f32::max);
+        Self::rounded(lo, hi)
+    }
+}
+
+impl From<f32> for Interval {
+    fn from(value: f32) -> Self {
+        Self::point(value)
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::rounded(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::rounded(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::hull([
+            self.lo * rhs.lo,
+            self.lo * rhs.hi,
+            self.hi * rhs.lo,
+            self.hi * rhs.hi,
+        ])
+    }
+}
+
+/// Dividing by an interval which contains zero gives the whole real line.
+impl Div for Interval {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.contains(0.) {
+            return Self::new(f32::NEG_INFINITY, f32::INFINITY);
+        }
+        Self::hull([
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ])
+    }
+}
+
+impl Scalar for Interval {
+    fn scale(self, factor: f32) -> Self {
+        self * Self::new(factor.next_down(), factor.next_up())
+    }
+
+    fn powi(self, power: i32) -> Self {
+        if power < 0 {
+            return Self::point(1.) / self.powi(-power);
+        }
+        // Computed in f64, whose rounding error is far below the outward rounding to f32.
+        let lo = math::powi_f64(self.lo.into(), power);
+        let hi = math::powi_f64(self.hi.into(), power);
+        if power % 2 == 1 || self.lo >= 0. {
+            Self::rounded(lo as f32, hi as f32)
+        } else if self.hi <= 0. {
+            Self::rounded(hi as f32, lo as f32)
+        } else {
+            // An even power of an interval containing zero has exactly zero as its lower bound.
+            Self::new(0., (lo.max(hi) as f32).next_up())
+        }
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.midpoint()
+    }
+}
+
+/// Written as `[lo, hi]`, with formatting options such as precision applying to both bounds.
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("[")?;
+        Display::fmt(&self.lo, f)?;
+        f.write_str(", ")?;
+        Display::fmt(&self.hi, f)?;
+        f.write_str("]")
+    }
+}
+
+impl LowerExp for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("[")?;
+        LowerExp::fmt(&self.lo, f)?;
+        f.write_str(", ")?;
+        LowerExp::fmt(&self.hi, f)?;
+        f.write_str("]")
+    }
+}
+
+impl UpperExp for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("[")?;
+        UpperExp::fmt(&self.lo, f)?;
+        f.write_str(", ")?;
+        UpperExp::fmt(&self.hi, f)?;
+        f.write_str("]")
+    }
+}
+
+impl<Kind: UnitKind> Mul<SingleUnit<Kind>> for Interval {
+    type Output = SingleQuantity<Kind, Interval>;
+
+    fn mul(self, rhs: SingleUnit<Kind>) -> Self::Output {
+        SingleQuantity::new(rhs.into(), self)
+    }
+}
+
+impl<Kind: UnitKind> SingleQuantity<Kind, Interval> {
+    /// Whether `value` lies within the interval, after converting it to the unit of `self`.
+    pub fn contains(&self, value: &SingleQuantity<Kind>) -> bool {
+        let value = value.to(self.unit);
+        self.scalar.contains(value.scalar)
+    }
+
+    /// The width of the interval, in the unit of `self`.
+    /// ```
+    /// use rus::scalar::Interval;
+    /// use rus::unit_defs as u;
+    /// let length = Interval::new(1.5, 2.5) * u::m;
+    /// assert_eq!(format!("{:.3}", length.to(u::cm).width()), "100.000 cm");
+    /// ```
+    pub fn width(&self) -> SingleQuantity<Kind> {
+        SingleQuantity::new(self.unit, self.scalar.width())
+    }
+}
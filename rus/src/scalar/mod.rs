@@ -0,0 +1,98 @@
+//! The numbers which can be the value of a quantity.
+//!
+//! A [`SingleQuantity`](crate::SingleQuantity) holds an `f32` by default, but any type
+//! implementing [`Scalar`] can be used instead, such as an [`Uncertain`] value, an
+//! [`Interval`], a [`Dual`] number for automatic differentiation, a [`Complex`] phasor or a
+//! primitive integer counting raw units, as described in [`IntegerScalar`].
+
+use crate::math;
+use crate::Ratio;
+use core::ops::{Add, Div, Mul, Sub};
+
+mod complex;
+pub use complex::Complex;
+mod dual;
+pub use dual::Dual;
+mod integer;
+pub use integer::{IntegerScalar, Rounding};
+mod interval;
+pub use interval::Interval;
+mod uncertain;
+pub use uncertain::Uncertain;
+
+/// Implemented by types which can be the value of a quantity.
+///
+/// Units only ever change a value by a real factor, given as an `f32` and, when it is known, as
+/// an exact [`Ratio`].
+pub trait Scalar:
+    Clone + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// Multiply by a real factor, such as the ratio between two units.
+    fn scale(self, factor: f32) -> Self;
+
+    /// Multiply by the ratio between two units, which is given exactly as well when the scales
+    /// of both units are known exactly. Only the `f32` factor is used unless this is overridden,
+    /// as it is for integers.
+    fn scale_exact(self, factor: f32, exact: Option<Ratio>) -> Self {
+        let _ = exact;
+        self.scale(factor)
+    }
+
+    /// Whether this value in a unit of scale `scale` equals `other` in a unit of scale
+    /// `other_scale`. `exact` is the ratio between the two units, when it is known exactly.
+    ///
+    /// Both values are scaled to the SI base units and compared unless this is overridden, as it
+    /// is for integers, which are compared exactly.
+    fn scaled_eq(&self, scale: f32, other: &Self, other_scale: f32, exact: Option<Ratio>) -> bool
+    where
+        Self: PartialEq,
+    {
+        let _ = exact;
+        self.clone().scale(scale) == other.clone().scale(other_scale)
+    }
+
+    /// Add two values, as the sum of two quantities does. This is `+` unless it is overridden, as
+    /// it is for integers so that an overflow panics in release builds too, instead of wrapping.
+    fn strict_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    /// Subtract two values, as the difference of two quantities does, panicking on overflow as
+    /// [`Scalar::strict_add`] does.
+    fn strict_sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    /// Multiply two values, as the product of two quantities does, panicking on overflow as
+    /// [`Scalar::strict_add`] does.
+    fn strict_mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    /// Divide two values, as the quotient of two quantities does, panicking on overflow as
+    /// [`Scalar::strict_add`] does.
+    fn strict_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+
+    /// Raise to an integer power.
+    fn powi(self, power: i32) -> Self;
+
+    /// A real value representing this scalar, used for choices such as the best prefix of a
+    /// quantity or the plural of a unit name.
+    fn to_f32(&self) -> f32;
+}
+
+impl Scalar for f32 {
+    fn scale(self, factor: f32) -> Self {
+        self * factor
+    }
+
+    fn powi(self, power: i32) -> Self {
+        math::powi(self, power)
+    }
+
+    fn to_f32(&self) -> f32 {
+        *self
+    }
+}
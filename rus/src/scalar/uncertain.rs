@@ -0,0 +1,250 @@
+use super::Scalar;
+use crate::kind::UnitKind;
+use crate::math;
+use crate::quantity::SingleQuantity;
+use crate::SingleUnit;
+use core::fmt::{Display, Formatter, LowerExp, Result, UpperExp};
+use core::ops::{Add, Div, Mul, Sub};
+use typenum::{Prod, Quot};
+
+/// A value with a standard uncertainty, written as `value ± sigma`.
+///
+/// Uncertainties are propagated to first order. The operators assume that their operands are
+/// independent, and methods such as [`Uncertain::mul_correlated`] take the correlation between
+/// the operands into account.
+///
+/// The value and the uncertainty are `f32`s, so an uncertainty below about `1e-38` is held with
+/// reduced precision, and one below about `1e-45` is zero.
+/// ```
+/// use rus::scalar::Uncertain;
+/// use rus::unit_defs as u;
+/// let length = Uncertain::new(12.3, 0.4) * u::cm;
+/// assert_eq!(format!("{length:.1}"), "12.3 ± 0.4 cm");
+/// assert_eq!(format!("{:.2}", length.to(u::m)), "0.12 ± 0.00 m");
+/// assert_eq!(format!("{:.3}", length.to(u::m)), "0.123 ± 0.004 m");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Uncertain {
+    /// The best estimate of the value.
+    pub value: f32,
+    /// The standard uncertainty of the value.
+    pub sigma: f32,
+}
+
+impl Uncertain {
+    /// Create a value with a standard uncertainty. The sign of `sigma` is ignored.
+    pub const fn new(value: f32, sigma: f32) -> Self {
+        Self {
+            value,
+            sigma: sigma.abs(),
+        }
+    }
+
+    /// Create a value with no uncertainty.
+    pub const fn exact(value: f32) -> Self {
+        Self::new(value, 0.)
+    }
+
+    /// The uncertainty relative to the size of the value.
+    pub fn relative(&self) -> f32 {
+        self.sigma / self.value.abs()
+    }
+
+    /// Add two values whose errors have the given correlation coefficient, between -1 and 1.
+    pub fn add_correlated(self, rhs: Self, correlation: f32) -> Self {
+        self.propagate(rhs, self.value + rhs.value, 1., 1., correlation)
+    }
+
+    /// Subtract two values whose errors have the given correlation coefficient, between -1 and 1.
+    /// ```
+    /// use rus::scalar::Uncertain;
+    /// let x = Uncertain::new(5., 0.3);
+    /// assert_eq!(x.sub_correlated(x, 1.), Uncertain::exact(0.));
+    /// ```
+    pub fn sub_correlated(self, rhs: Self, correlation: f32) -> Self {
+        self.propagate(rhs, self.value - rhs.value, 1., -1., correlation)
+    }
+
+    /// Multiply two values whose errors have the given correlation coefficient, between -1 and 1.
+    pub fn mul_correlated(self, rhs: Self, correlation: f32) -> Self {
+        let value = self.value * rhs.value;
+        self.propagate(rhs, value, rhs.value, self.value, correlation)
+    }
+
+    /// Divide two values whose errors have the given correlation coefficient, between -1 and 1.
+    pub fn div_correlated(self, rhs: Self, correlation: f32) -> Self {
+        let value = self.value / rhs.value;
+        let rhs_derivative = -value / rhs.value;
+        self.propagate(rhs, value, 1. / rhs.value, rhs_derivative, correlation)
+    }
+
+    /// The result of a function of `self` and `rhs` with the given value and partial derivatives.
+    fn propagate(
+        self,
+        rhs: Self,
+        value: f32,
+        lhs_derivative: f32,
+        rhs_derivative: f32,
+        correlation: f32,
+    ) -> Self {
+        let lhs_error = lhs_derivative * self.sigma;
+        let rhs_error = rhs_derivative * rhs.sigma;
+        // The errors are divided by the larger one before they are squared, so that errors below
+        // about 1e-19, such as that of the Planck constant, do not square to zero.
+        let scale = lhs_error.abs().max(rhs_error.abs());
+        if scale == 0. || scale.is_infinite() {
+            return Self::new(value, scale);
+        }
+        let (lhs_error, rhs_error) = (lhs_error / scale, rhs_error / scale);
+        let variance = lhs_error * lhs_error
+            + rhs_error * rhs_error
+            + 2. * correlation * lhs_error * rhs_error;
+        // Rounding can make the variance of perfectly correlated values slightly negative.
+        Self::new(value, scale * math::sqrt(variance.max(0.)))
+    }
+}
+
+impl From<f32> for Uncertain {
+    fn from(value: f32) -> Self {
+        Self::exact(value)
+    }
+}
+
+impl Add for Uncertain {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_correlated(rhs, 0.)
+    }
+}
+
+impl Sub for Uncertain {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.sub_correlated(rhs, 0.)
+    }
+}
+
+impl Mul for Uncertain {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_correlated(rhs, 0.)
+    }
+}
+
+impl Div for Uncertain {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_correlated(rhs, 0.)
+    }
+}
+
+impl Scalar for Uncertain {
+    fn scale(self, factor: f32) -> Self {
+        Self::new(self.value * factor, self.sigma * factor)
+    }
+
+    fn powi(self, power: i32) -> Self {
+        let derivative = power as f32 * math::powi(self.value, power - 1);
+        Self::new(math::powi(self.value, power), derivative * self.sigma)
+    }
+
+    fn to_f32(&self) -> f32 {
+        self.value
+    }
+}
+
+/// Formatting options such as precision apply to both the value and the uncertainty.
+impl Display for Uncertain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&self.value, f)?;
+        f.write_str(" ± ")?;
+        Display::fmt(&self.sigma, f)
+    }
+}
+
+impl LowerExp for Uncertain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerExp::fmt(&self.value, f)?;
+        f.write_str(" ± ")?;
+        LowerExp::fmt(&self.sigma, f)
+    }
+}
+
+impl UpperExp for Uncertain {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperExp::fmt(&self.value, f)?;
+        f.write_str(" ± ")?;
+        UpperExp::fmt(&self.sigma, f)
+    }
+}
+
+impl<Kind: UnitKind> Mul<SingleUnit<Kind>> for Uncertain {
+    type Output = SingleQuantity<Kind, Uncertain>;
+
+    fn mul(self, rhs: SingleUnit<Kind>) -> Self::Output {
+        SingleQuantity::new(rhs.into(), self)
+    }
+}
+
+impl<Kind: UnitKind> SingleQuantity<Kind, Uncertain> {
+    /// Add two quantities whose errors have the given correlation coefficient, as in
+    /// [`Uncertain::add_correlated`]. The result has the unit of `self`.
+    pub fn add_correlated(self, rhs: Self, correlation: f32) -> Self {
+        let rhs = rhs.to(self.unit);
+        Self::new(
+            self.unit,
+            self.scalar.add_correlated(rhs.scalar, correlation),
+        )
+    }
+
+    /// Subtract two quantities whose errors have the given correlation coefficient, as in
+    /// [`Uncertain::sub_correlated`]. The result has the unit of `self`.
+    pub fn sub_correlated(self, rhs: Self, correlation: f32) -> Self {
+        let rhs = rhs.to(self.unit);
+        Self::new(
+            self.unit,
+            self.scalar.sub_correlated(rhs.scalar, correlation),
+        )
+    }
+
+    /// Multiply two quantities whose errors have the given correlation coefficient, as in
+    /// [`Uncertain::mul_correlated`].
+    /// ```
+    /// use rus::scalar::Uncertain;
+    /// use rus::unit_defs as u;
+    /// let side = Uncertain::new(2., 0.1) * u::m;
+    /// let area = side.mul_correlated(side, 1.);
+    /// assert_eq!(format!("{area:.1}"), "4.0 ± 0.4 m^2");
+    /// ```
+    pub fn mul_correlated<Kind2: UnitKind>(
+        self,
+        rhs: SingleQuantity<Kind2, Uncertain>,
+        correlation: f32,
+    ) -> SingleQuantity<Prod<Kind, Kind2>, Uncertain>
+    where
+        Kind: Mul<Kind2>,
+        Prod<Kind, Kind2>: UnitKind,
+    {
+        let scalar = self.scalar.mul_correlated(rhs.scalar, correlation);
+        SingleQuantity::new(self.unit * rhs.unit, scalar)
+    }
+
+    /// Divide two quantities whose errors have the given correlation coefficient, as in
+    /// [`Uncertain::div_correlated`].
+    pub fn div_correlated<Kind2: UnitKind>(
+        self,
+        rhs: SingleQuantity<Kind2, Uncertain>,
+        correlation: f32,
+    ) -> SingleQuantity<Quot<Kind, Kind2>, Uncertain>
+    where
+        Kind: Div<Kind2>,
+        Quot<Kind, Kind2>: UnitKind,
+    {
+        let scalar = self.scalar.div_correlated(rhs.scalar, correlation);
+        SingleQuantity::new(self.unit / rhs.unit, scalar)
+    }
+}
@@ -0,0 +1,289 @@
+//! Quantities whose unit is known at compile time.
+//!
+//! A [`SingleQuantity`] carries its unit at runtime, which allows any unit to be chosen while the
+//! program runs. When the unit is always the same, it can instead be encoded in the type of a
+//! [`StaticQuantity`] using a marker type implementing [`StaticUnit`]. Such quantities are the
+//! size of their scalar, and converting between units is a single multiplication by a factor
+//! computed at compile time.
+//!
+//! Marker types are created from existing units with [`create_static_unit!`](crate::create_static_unit).
+//! ```
+//! use rus::create_static_unit;
+//! use rus::kinds::{LengthUnit, TimeUnit};
+//! use rus::static_unit::{Per, StaticQuantity};
+//! use rus::unit_defs as u;
+//!
+//! create_static_unit!(Km: LengthUnit = u::km);
+//! create_static_unit!(M: LengthUnit = u::m);
+//! create_static_unit!(H: TimeUnit = u::h);
+//! create_static_unit!(S: TimeUnit = u::s);
+//!
+//! let distance = 42.195 * Km;
+//! assert_eq!(format!("{:.0}", distance.to::<M>()), "42195 m");
+//! let pace = distance / (2. * H);
+//! let speed: StaticQuantity<Per<M, S>> = pace.to();
+//! assert_eq!(format!("{speed:.2}"), "5.86 m s^-1");
+//! assert_eq!(size_of_val(&speed), size_of::<f32>());
+//! ```
+//!
+//! Only units of the same kind can be converted between, which is checked at compile time.
+//! ```compile_fail
+//! use rus::create_static_unit;
+//! use rus::kinds::{LengthUnit, TimeUnit};
+//! use rus::unit_defs as u;
+//!
+//! create_static_unit!(Km: LengthUnit = u::km);
+//! create_static_unit!(H: TimeUnit = u::h);
+//!
+//! let time = (5. * Km).to::<H>();
+//! ```
+//!
+//! Static quantities can be turned into runtime ones, for example to be combined with quantities
+//! whose unit is only known at runtime.
+//! ```
+//! use rus::create_static_unit;
+//! use rus::kinds::MassUnit;
+//! use rus::unit_defs as u;
+//! use rus::SingleQuantity;
+//!
+//! create_static_unit!(Kg: MassUnit = u::kg);
+//!
+//! let mass: SingleQuantity<_> = (2. * Kg).into();
+//! assert_eq!(mass + 500. * u::g, 2.5 * u::kg);
+//! ```
+
+use crate::__private::{exact_div, exact_mul};
+use crate::kind::UnitKind;
+use crate::quantity::SingleQuantity;
+use crate::scalar::{IntegerScalar, Rounding, Scalar};
+use crate::{CompositeUnit, Ratio};
+use core::fmt::{Debug, Display, Formatter, LowerExp, Result, UpperExp};
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Sub};
+use typenum::{Prod, Quot};
+
+/// A unit which is known at compile time. Implemented by the marker types created with
+/// [`create_static_unit!`](crate::create_static_unit), and by products and quotients of them.
+pub trait StaticUnit {
+    /// The kind of the unit.
+    type Kind: UnitKind;
+
+    /// The scale from the SI base unit equivalent.
+    const SCALE: f32;
+
+    /// The exact scale from the SI base unit equivalent, if it is known.
+    const EXACT_SCALE: Option<Ratio>;
+
+    /// The unit as a runtime unit, used to write the unit of a quantity.
+    fn unit() -> CompositeUnit<Self::Kind>;
+}
+
+/// The product of two static units.
+pub struct Times<U, V>(PhantomData<(U, V)>);
+
+impl<U: StaticUnit, V: StaticUnit> StaticUnit for Times<U, V>
+where
+    U::Kind: Mul<V::Kind>,
+    Prod<U::Kind, V::Kind>: UnitKind,
+{
+    type Kind = Prod<U::Kind, V::Kind>;
+
+    const SCALE: f32 = U::SCALE * V::SCALE;
+
+    const EXACT_SCALE: Option<Ratio> = exact_mul(U::EXACT_SCALE, V::EXACT_SCALE);
+
+    fn unit() -> CompositeUnit<Self::Kind> {
+        U::unit() * V::unit()
+    }
+}
+
+impl<U, V> Debug for Times<U, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("Times")
+    }
+}
+
+/// The quotient of two static units.
+pub struct Per<U, V>(PhantomData<(U, V)>);
+
+impl<U: StaticUnit, V: StaticUnit> StaticUnit for Per<U, V>
+where
+    U::Kind: Div<V::Kind>,
+    Quot<U::Kind, V::Kind>: UnitKind,
+{
+    type Kind = Quot<U::Kind, V::Kind>;
+
+    const SCALE: f32 = U::SCALE / V::SCALE;
+
+    const EXACT_SCALE: Option<Ratio> = exact_div(U::EXACT_SCALE, V::EXACT_SCALE);
+
+    fn unit() -> CompositeUnit<Self::Kind> {
+        U::unit() / V::unit()
+    }
+}
+
+impl<U, V> Debug for Per<U, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("Per")
+    }
+}
+
+/// The factor converting a value in unit `U` to a value in unit `V`.
+struct Conversion<U, V>(PhantomData<(U, V)>);
+
+impl<U: StaticUnit, V: StaticUnit> Conversion<U, V> {
+    const FACTOR: f32 = U::SCALE / V::SCALE;
+    const EXACT_FACTOR: Option<Ratio> = exact_div(U::EXACT_SCALE, V::EXACT_SCALE);
+}
+
+/// A quantity whose unit `U` is part of its type, so only its scalar is stored.
+#[repr(transparent)]
+pub struct StaticQuantity<U, S = f32> {
+    scalar: S,
+    _unit_marker: PhantomData<U>,
+}
+
+impl<U, S> StaticQuantity<U, S> {
+    /// Create a new quantity from a scalar in the unit `U`.
+    pub const fn new(scalar: S) -> Self {
+        Self {
+            scalar,
+            _unit_marker: PhantomData,
+        }
+    }
+
+    /// The scalar of this quantity, in the unit `U`.
+    pub fn scalar(&self) -> &S {
+        &self.scalar
+    }
+}
+
+impl<U: StaticUnit, S: Scalar> StaticQuantity<U, S> {
+    /// Convert a quantity to another unit of the same kind.
+    /// ```
+    /// use rus::create_static_unit;
+    /// use rus::kinds::LengthUnit;
+    /// use rus::imperial;
+    ///
+    /// create_static_unit!(Ft: LengthUnit = imperial::ft);
+    /// create_static_unit!(Inch: LengthUnit = imperial::inch);
+    /// assert_eq!((2. * Ft).to::<Inch>().scalar(), &24.);
+    /// ```
+    pub fn to<V: StaticUnit<Kind = U::Kind>>(self) -> StaticQuantity<V, S> {
+        StaticQuantity::new(
+            self.scalar
+                .scale_exact(Conversion::<U, V>::FACTOR, Conversion::<U, V>::EXACT_FACTOR),
+        )
+    }
+}
+
+impl<U: StaticUnit, S: IntegerScalar> StaticQuantity<U, S> {
+    /// Convert a quantity to another unit of the same kind, rounding as given. Returns `None` if
+    /// the result does not fit in the integer type.
+    /// ```
+    /// use rus::create_static_unit;
+    /// use rus::kinds::LengthUnit;
+    /// use rus::scalar::Rounding;
+    /// use rus::unit_defs as u;
+    ///
+    /// create_static_unit!(Mm: LengthUnit = u::mm);
+    /// create_static_unit!(M: LengthUnit = u::m);
+    /// assert_eq!((1500_i32 * Mm).checked_to::<M>(Rounding::Down).unwrap().scalar(), &1);
+    /// assert_eq!((1500_i32 * Mm).to::<M>().scalar(), &2);
+    /// assert!((5_i8 * M).checked_to::<Mm>(Rounding::Nearest).is_none());
+    /// ```
+    pub fn checked_to<V: StaticUnit<Kind = U::Kind>>(
+        self,
+        rounding: Rounding,
+    ) -> Option<StaticQuantity<V, S>> {
+        let scalar = self.scalar.checked_scale_exact(
+            Conversion::<U, V>::FACTOR,
+            Conversion::<U, V>::EXACT_FACTOR,
+            rounding,
+        )?;
+        Some(StaticQuantity::new(scalar))
+    }
+}
+
+impl<U: StaticUnit, S> From<StaticQuantity<U, S>> for SingleQuantity<U::Kind, S> {
+    fn from(quantity: StaticQuantity<U, S>) -> Self {
+        SingleQuantity::new(U::unit(), quantity.scalar)
+    }
+}
+
+impl<U, S: Clone> Clone for StaticQuantity<U, S> {
+    fn clone(&self) -> Self {
+        Self::new(self.scalar.clone())
+    }
+}
+
+impl<U, S: Copy> Copy for StaticQuantity<U, S> {}
+
+impl<U, S: Debug> Debug for StaticQuantity<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("StaticQuantity").field(&self.scalar).finish()
+    }
+}
+
+impl<U, S: PartialEq> PartialEq for StaticQuantity<U, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.scalar == other.scalar
+    }
+}
+
+impl<U, S: PartialOrd> PartialOrd for StaticQuantity<U, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.scalar.partial_cmp(&other.scalar)
+    }
+}
+
+impl<U, S: Scalar> Add for StaticQuantity<U, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.scalar.strict_add(rhs.scalar))
+    }
+}
+
+impl<U, S: Scalar> Sub for StaticQuantity<U, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.scalar.strict_sub(rhs.scalar))
+    }
+}
+
+impl<U, V, S: Scalar> Mul<StaticQuantity<V, S>> for StaticQuantity<U, S> {
+    type Output = StaticQuantity<Times<U, V>, S>;
+
+    fn mul(self, rhs: StaticQuantity<V, S>) -> Self::Output {
+        StaticQuantity::new(self.scalar.strict_mul(rhs.scalar))
+    }
+}
+
+impl<U, V, S: Scalar> Div<StaticQuantity<V, S>> for StaticQuantity<U, S> {
+    type Output = StaticQuantity<Per<U, V>, S>;
+
+    fn div(self, rhs: StaticQuantity<V, S>) -> Self::Output {
+        StaticQuantity::new(self.scalar.strict_div(rhs.scalar))
+    }
+}
+
+/// Written in the same way as a [`SingleQuantity`] in the unit `U`.
+impl<U: StaticUnit, S: Scalar + Display> Display for StaticQuantity<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(&SingleQuantity::from(self.clone()), f)
+    }
+}
+
+impl<U: StaticUnit, S: Scalar + LowerExp> LowerExp for StaticQuantity<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        LowerExp::fmt(&SingleQuantity::from(self.clone()), f)
+    }
+}
+
+impl<U: StaticUnit, S: Scalar + UpperExp> UpperExp for StaticQuantity<U, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        UpperExp::fmt(&SingleQuantity::from(self.clone()), f)
+    }
+}
@@ -0,0 +1,80 @@
+//! Units of the centimeter–gram–second system, in its Gaussian form.
+//!
+//! Quantities in this library are always measured in SI dimensions, so the electromagnetic units
+//! are defined by their SI equivalents. For example, a statcoulomb is a
+//! [`ChargeUnit`](crate::kinds::ChargeUnit) rather than having the fractional dimensions it has in
+//! the Gaussian system.
+//! ```
+//! use rus::cgs;
+//! use rus::unit_defs as u;
+//! let force = 2e5 * cgs::dyne;
+//! assert_eq!(format!("{:.1}", force.to(u::N)), "2.0 N");
+//! let pressure = 10. * cgs::Ba;
+//! assert_eq!(format!("{:.1}", pressure.to(u::N / u::m / u::m)), "1.0 N m^-2");
+//! ```
+
+use crate::kinds::*;
+use crate::DynUnit;
+use rus_macros::{create_unit, create_unit_with_prefixes};
+
+pub use crate::unit_defs::{cm, g, s};
+use crate::unit_defs::{kg, A};
+
+create_unit!(
+    /// A dyne. The CGS unit of force.
+    dyne: ForceUnit = g * cm / s^2,
+    "dyne" "dyn"
+);
+create_unit!(
+    /// An erg. The CGS unit of energy.
+    erg: EnergyUnit = dyne * cm,
+    "erg"
+);
+create_unit!(
+    /// A barye. The CGS unit of pressure.
+    Ba: PressureUnit = dyne / cm^2,
+    "barye",
+    aliases = [barye]
+);
+create_unit_with_prefixes!(
+    /// A poise. The CGS unit of dynamic viscosity.
+    P: DynamicViscosityUnit = Ba * s,
+    "poise",
+    prefixes = [c],
+    aliases = [poise]
+);
+create_unit_with_prefixes!(
+    /// A stokes. The CGS unit of kinematic viscosity.
+    St: KinematicViscosityUnit = cm^2 / s,
+    "stokes",
+    prefixes = [c],
+    aliases = [stokes]
+);
+create_unit!(
+    /// A gauss. The Gaussian unit of magnetic flux density, one ten-thousandth of a tesla.
+    G: MagneticFluxDensityUnit = 1e-4 * kg / (s^2 * A),
+    "gauss",
+    aliases = [gauss]
+);
+create_unit!(
+    /// A statcoulomb. The Gaussian unit of electric charge, the charge which repels an equal
+    /// charge one centimeter away with a force of one dyne.
+    statC: ChargeUnit = 1e-1 / 299_792_458 * A * s,
+    "statcoulomb" "statC",
+    aliases = [statcoulomb]
+);
+
+/// The units of the CGS system, for looking units up by name with [`DynUnit::find`] and
+/// [`CompositeUnit::parse`](crate::CompositeUnit::parse).
+pub const UNITS: &[DynUnit] = &[
+    cm.to_dyn(),
+    g.to_dyn(),
+    s.to_dyn(),
+    dyne.to_dyn(),
+    erg.to_dyn(),
+    Ba.to_dyn(),
+    P.to_dyn(),
+    St.to_dyn(),
+    G.to_dyn(),
+    statC.to_dyn(),
+];
@@ -1,7 +1,10 @@
-use super::{single::ToSingle, DynUnit, SingleUnit, UnitKind};
-use std::fmt::Display;
-use std::marker::PhantomData;
-use std::ops::{Div, Mul};
+use super::format::{write_unit, UnitFormat};
+use super::list::{capacity_exceeded, UnitList, CAPACITY};
+use super::{Ratio, SingleUnit, UnitKind};
+use crate::math;
+use core::fmt::Display;
+use core::marker::PhantomData;
+use core::ops::{Div, Mul};
 use typenum::{Prod, Quot};
 
 /// Represents a product and/or quotient of many units.
@@ -9,14 +12,22 @@ use typenum::{Prod, Quot};
 /// For example, `m/s` is a composite unit.
 /// Composite units can be created using arithmetic on other units.
 /// `m/s` can be created as `u::m / u::s`.
+///
+/// The units are stored inline, so composite units are `Copy` and arithmetic on them never
+/// allocates. A composite unit can be made of at most sixteen distinct units. Arithmetic on units
+/// which would need more panics, while [`CompositeUnit::checked_mul`] and
+/// [`CompositeUnit::checked_div`] return `None` instead. Arithmetic on quantities does not panic
+/// when their units do not fit, since the result is written in SI base units instead. For integer
+/// quantities this conversion rounds, and panics if the result overflows, as
+/// [`SingleQuantity::checked_mul`](crate::SingleQuantity::checked_mul) describes.
 #[derive(Debug)]
 pub struct CompositeUnit<Kind: UnitKind> {
-    pub(super) component_units: Vec<(DynUnit, i8)>,
+    pub(crate) component_units: UnitList,
     _kind_marker: PhantomData<Kind>,
 }
 
 impl<Kind: UnitKind> CompositeUnit<Kind> {
-    pub(super) fn new(units: Vec<(DynUnit, i8)>) -> Self {
+    pub(crate) const fn new(units: UnitList) -> Self {
         Self {
             component_units: units,
             _kind_marker: PhantomData,
@@ -25,13 +36,28 @@ impl<Kind: UnitKind> CompositeUnit<Kind> {
 
     pub(crate) fn scale_factor(&self) -> f32 {
         let mut res = 1.;
-        for (unit, power) in &self.component_units {
-            for _ in 0..*power {
-                res *= unit.scale;
-            }
+        for (unit, power) in self.component_units.iter() {
+            res *= math::powi(unit.scale(), power.into());
         }
         res
     }
+
+    /// The exact factor which a value in this unit must be multiplied by to be in `target`, if
+    /// the scales of all of their units are known exactly.
+    pub(crate) fn exact_factor_to(&self, target: &Self) -> Option<Ratio> {
+        self.exact_scale_factor()?
+            .checked_div(target.exact_scale_factor()?)
+    }
+
+    /// The exact scale from the SI base unit equivalent, if the scales of all of its units are
+    /// known exactly.
+    pub(crate) fn exact_scale_factor(&self) -> Option<Ratio> {
+        let mut res = Ratio::ONE;
+        for (unit, power) in self.component_units.iter() {
+            res = res.checked_mul(unit.exact_scale()?.checked_powi(power.into())?)?;
+        }
+        Some(res)
+    }
 }
 
 impl<Kind: UnitKind> PartialEq for CompositeUnit<Kind> {
@@ -42,15 +68,66 @@ impl<Kind: UnitKind> PartialEq for CompositeUnit<Kind> {
 
 impl<Kind: UnitKind> Clone for CompositeUnit<Kind> {
     fn clone(&self) -> Self {
-        Self {
-            component_units: self.component_units.clone(),
-            _kind_marker: PhantomData,
+        *self
+    }
+}
+
+impl<Kind: UnitKind> Copy for CompositeUnit<Kind> {}
+
+/// Combine `units` with `other` raised to the power `sign`, which is `1` to multiply and `-1` to
+/// divide.
+///
+/// Units whose powers cancel are removed before the new units are added, so that a result which
+/// fits is never rejected. Returns `None` if the result has more than [`CAPACITY`] distinct
+/// units.
+fn combine(mut units: UnitList, other: &UnitList, sign: i8) -> Option<UnitList> {
+    let mut unmatched = [false; CAPACITY];
+    for ((unit2, power2), unmatched) in other.iter().zip(&mut unmatched) {
+        match units.iter_mut().find(|(unit1, _)| *unit1 == unit2) {
+            Some((_, power1)) => *power1 += sign * power2,
+            None => *unmatched = true,
+        }
+    }
+    units.retain(|_, power| power != 0);
+    for ((unit2, power2), unmatched) in other.iter().zip(unmatched) {
+        if unmatched {
+            units.try_push((unit2, sign * power2))?;
         }
     }
+    Some(units)
 }
 
-impl<Kind: UnitKind> ToSingle for CompositeUnit<Kind> {
-    type Single = SingleUnit<Kind>;
+impl<Kind1: UnitKind> CompositeUnit<Kind1> {
+    /// Multiply two units, or return `None` if the product would be made of more distinct units
+    /// than a composite unit can hold.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let speed = u::km / u::h;
+    /// assert_eq!(speed.checked_mul(u::h.into()).unwrap().to_string(), "km");
+    /// ```
+    pub fn checked_mul<Kind2: UnitKind>(
+        self,
+        rhs: CompositeUnit<Kind2>,
+    ) -> Option<CompositeUnit<Prod<Kind1, Kind2>>>
+    where
+        Kind1: Mul<Kind2>,
+        Prod<Kind1, Kind2>: UnitKind,
+    {
+        combine(self.component_units, &rhs.component_units, 1).map(CompositeUnit::new)
+    }
+
+    /// Divide two units, or return `None` if the quotient would be made of more distinct units
+    /// than a composite unit can hold.
+    pub fn checked_div<Kind2: UnitKind>(
+        self,
+        rhs: CompositeUnit<Kind2>,
+    ) -> Option<CompositeUnit<Quot<Kind1, Kind2>>>
+    where
+        Kind1: Div<Kind2>,
+        Quot<Kind1, Kind2>: UnitKind,
+    {
+        combine(self.component_units, &rhs.component_units, -1).map(CompositeUnit::new)
+    }
 }
 
 impl<Kind1: UnitKind, Kind2: UnitKind> Mul<CompositeUnit<Kind2>> for CompositeUnit<Kind1>
@@ -59,26 +136,9 @@ where
     Prod<Kind1, Kind2>: UnitKind,
 {
     type Output = CompositeUnit<Prod<Kind1, Kind2>>;
-    fn mul(mut self, rhs: CompositeUnit<Kind2>) -> Self::Output {
-        let mut new_units = Vec::new();
-        for (unit2, power2) in &rhs.component_units {
-            let mut matched_unit = false;
-            for (unit1, power1) in &mut self.component_units {
-                if unit1 == unit2 {
-                    *power1 += *power2;
-                    matched_unit = false;
-                    break;
-                }
-            }
-            if !matched_unit {
-                new_units.push((*unit2, *power2));
-            }
-        }
-        self.component_units.append(&mut new_units);
-        CompositeUnit {
-            component_units: self.component_units,
-            _kind_marker: PhantomData,
-        }
+
+    fn mul(self, rhs: CompositeUnit<Kind2>) -> Self::Output {
+        self.checked_mul(rhs).unwrap_or_else(|| capacity_exceeded())
     }
 }
 
@@ -88,26 +148,9 @@ where
     Quot<Kind1, Kind2>: UnitKind,
 {
     type Output = CompositeUnit<Quot<Kind1, Kind2>>;
-    fn div(mut self, rhs: CompositeUnit<Kind2>) -> Self::Output {
-        let mut new_units = Vec::new();
-        for (unit2, power2) in &rhs.component_units {
-            let mut matched_unit = false;
-            for (unit1, power1) in &mut self.component_units {
-                if unit1 == unit2 {
-                    *power1 -= *power2;
-                    matched_unit = false;
-                    break;
-                }
-            }
-            if !matched_unit {
-                new_units.push((*unit2, -*power2));
-            }
-        }
-        self.component_units.append(&mut new_units);
-        CompositeUnit {
-            component_units: self.component_units,
-            _kind_marker: PhantomData,
-        }
+
+    fn div(self, rhs: CompositeUnit<Kind2>) -> Self::Output {
+        self.checked_div(rhs).unwrap_or_else(|| capacity_exceeded())
     }
 }
 
@@ -118,26 +161,8 @@ where
 {
     type Output = CompositeUnit<Prod<Kind1, Kind2>>;
 
-    fn mul(mut self, rhs: SingleUnit<Kind2>) -> Self::Output {
-        let rhs = rhs.into();
-        for (i, (unit, power)) in self.component_units.iter_mut().enumerate() {
-            if *unit == rhs {
-                if *power == -1 {
-                    self.component_units.swap_remove(i);
-                } else {
-                    *power += 1;
-                }
-                return CompositeUnit {
-                    _kind_marker: PhantomData,
-                    component_units: self.component_units,
-                };
-            }
-        }
-        self.component_units.push((rhs, 1));
-        CompositeUnit {
-            _kind_marker: PhantomData,
-            component_units: self.component_units,
-        }
+    fn mul(self, rhs: SingleUnit<Kind2>) -> Self::Output {
+        self * CompositeUnit::from(rhs)
     }
 }
 
@@ -148,26 +173,8 @@ where
 {
     type Output = CompositeUnit<Quot<Kind1, Kind2>>;
 
-    fn div(mut self, rhs: SingleUnit<Kind2>) -> Self::Output {
-        let rhs = rhs.into();
-        for (i, (unit, power)) in self.component_units.iter_mut().enumerate() {
-            if *unit == rhs {
-                if *power == 1 {
-                    self.component_units.swap_remove(i);
-                } else {
-                    *power -= 1;
-                }
-                return CompositeUnit {
-                    _kind_marker: PhantomData,
-                    component_units: self.component_units,
-                };
-            }
-        }
-        self.component_units.push((rhs, -1));
-        CompositeUnit {
-            _kind_marker: PhantomData,
-            component_units: self.component_units,
-        }
+    fn div(self, rhs: SingleUnit<Kind2>) -> Self::Output {
+        self / CompositeUnit::from(rhs)
     }
 }
 
@@ -175,23 +182,24 @@ impl<Kind: UnitKind> From<SingleUnit<Kind>> for CompositeUnit<Kind> {
     fn from(other: SingleUnit<Kind>) -> Self {
         CompositeUnit {
             _kind_marker: PhantomData,
-            component_units: vec![(other.into(), 1)],
+            component_units: [(other.into(), 1)].into(),
         }
     }
 }
 
+/// Composite units are written as ASCII, with their units separated by spaces and powers written
+/// with `^`, such as `m s^-2`. The alternate flag (`{:#}`) instead separates them with middle dots
+/// and writes powers as superscripts, such as `m·s⁻²`. Other formats are available through
+/// [`CompositeUnit::format`].
+/// ```
+/// use rus::unit_defs as u;
+/// let acceleration = u::m / u::s / u::s;
+/// assert_eq!(format!("{acceleration}"), "m s^-2");
+/// assert_eq!(format!("{acceleration:#}"), "m·s⁻²");
+/// ```
 impl<Kind: UnitKind> Display for CompositeUnit<Kind> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (i, (unit, power)) in self.component_units.iter().enumerate() {
-            match (i, power) {
-                (_, 0) => {}
-                (0, 1) => write!(f, "{}", unit.abbreviation)?,
-                (0, _) => write!(f, "{}^{}", unit.abbreviation, power)?,
-                (_, 1) => write!(f, " {}", unit.abbreviation)?,
-                (_, _) => write!(f, " {}^{}", unit.abbreviation, power)?,
-            }
-        }
-        Ok(())
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_unit(f, &self.component_units, UnitFormat::display(f.alternate()))
     }
 }
 
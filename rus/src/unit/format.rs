@@ -0,0 +1,531 @@
+//! Configurable formatting of units and quantities.
+//!
+//! The [`Display`] implementations of [`CompositeUnit`] and [`SingleQuantity`] cover the common
+//! cases. For anything else, build a [`UnitFormat`] and pass it to [`CompositeUnit::format`] or
+//! [`SingleQuantity::format`].
+//! ```
+//! use rus::format::{Separator, UnitFormat, UnitStyle, ValueSeparator};
+//! use rus::unit_defs as u;
+//! let acceleration = 9.8 * u::m / u::s / u::s;
+//!
+//! let fraction = UnitFormat::new().style(UnitStyle::Fraction);
+//! assert_eq!(acceleration.format(fraction).to_string(), "9.8 m / s^2");
+//!
+//! let dotted = UnitFormat::new().separator(Separator::MiddleDot);
+//! let force = 2. * u::kg * u::m / u::s / u::s;
+//! assert_eq!(force.format(dotted).to_string(), "2 kg·m·s^-2");
+//!
+//! let latex = UnitFormat::new().style(UnitStyle::Latex);
+//! assert_eq!(acceleration.format(latex).to_string(), r"\SI{9.8}{\metre\per\second\squared}");
+//!
+//! let long = UnitFormat::new().style(UnitStyle::LongName);
+//! assert_eq!(acceleration.format(long).to_string(), "9.8 meters per second squared");
+//!
+//! let thin = UnitFormat::new().value_separator(ValueSeparator::Thin);
+//! assert_eq!(format!("{:.2}", (1.5 * u::m).format(thin)), "1.50\u{2009}m");
+//!
+//! let resistance = 4.7 * u::kohm;
+//! assert_eq!(resistance.format(UnitFormat::ascii()).to_string(), "4.7 kohm");
+//! ```
+
+use super::list::UnitList;
+use super::{CompositeUnit, DynUnit, UnitKind};
+use crate::quantity::SingleQuantity;
+use crate::scalar::Scalar;
+use core::fmt::{Display, Formatter, LowerExp, Result, UpperExp, Write};
+
+/// Options for writing a [`CompositeUnit`] or a [`SingleQuantity`].
+///
+/// The default is the format used by [`Display`]: `m s^-2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct UnitFormat {
+    style: UnitStyle,
+    separator: Separator,
+    exponents: Exponents,
+    value_separator: ValueSeparator,
+    symbols: Symbols,
+}
+
+impl UnitFormat {
+    /// Create the default format, `m s^-2`.
+    pub const fn new() -> Self {
+        Self {
+            style: UnitStyle::NegativeExponents,
+            separator: Separator::Space,
+            exponents: Exponents::Caret,
+            value_separator: ValueSeparator::Space,
+            symbols: Symbols::Unicode,
+        }
+    }
+
+    /// Create a format which only writes ASCII, `um s^-2`, for output which cannot hold Unicode.
+    /// ```
+    /// use rus::format::UnitFormat;
+    /// use rus::unit_defs as u;
+    /// let angle = 1.5 * u::deg / u::us;
+    /// assert_eq!(angle.to_string(), "1.5 ° μs^-1");
+    /// assert_eq!(angle.format(UnitFormat::ascii()).to_string(), "1.5 deg us^-1");
+    /// ```
+    pub const fn ascii() -> Self {
+        Self::new().symbols(Symbols::Ascii)
+    }
+
+    /// The format used by [`Display`], where `alternate` is whether the alternate flag (`{:#}`)
+    /// was given.
+    pub(crate) const fn display(alternate: bool) -> Self {
+        if alternate {
+            Self::new()
+                .separator(Separator::MiddleDot)
+                .exponents(Exponents::Superscript)
+        } else {
+            Self::new()
+        }
+    }
+
+    /// Set how the units are laid out.
+    pub const fn style(mut self, style: UnitStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set what goes between multiplied units. Only used by [`UnitStyle::NegativeExponents`] and
+    /// [`UnitStyle::Fraction`].
+    pub const fn separator(mut self, separator: Separator) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set how powers are written. Only used by [`UnitStyle::NegativeExponents`] and
+    /// [`UnitStyle::Fraction`].
+    pub const fn exponents(mut self, exponents: Exponents) -> Self {
+        self.exponents = exponents;
+        self
+    }
+
+    /// Set what goes between the value and the unit of a quantity. Not used by
+    /// [`UnitStyle::Latex`].
+    pub const fn value_separator(mut self, value_separator: ValueSeparator) -> Self {
+        self.value_separator = value_separator;
+        self
+    }
+
+    /// Set how the abbreviations of units are spelled. Only used by
+    /// [`UnitStyle::NegativeExponents`] and [`UnitStyle::Fraction`].
+    pub const fn symbols(mut self, symbols: Symbols) -> Self {
+        self.symbols = symbols;
+        self
+    }
+}
+
+/// How the units of a composite unit are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnitStyle {
+    /// Every unit in a row, with negative powers for divided units: `m s^-2`.
+    #[default]
+    NegativeExponents,
+    /// Divided units after a slash: `m / s^2`.
+    Fraction,
+    /// LaTeX using the `siunitx` package: `\si{\metre\per\second\squared}`.
+    ///
+    /// Units which `siunitx` does not know are written with their abbreviation.
+    Latex,
+    /// The full names of the units: `meters per second squared`.
+    LongName,
+}
+
+/// What goes between multiplied units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Separator {
+    /// A space: `kg m`.
+    #[default]
+    Space,
+    /// A middle dot: `kg·m`.
+    MiddleDot,
+}
+
+impl Separator {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Space => " ",
+            Self::MiddleDot => "·",
+        }
+    }
+}
+
+/// What goes between the value and the unit of a quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValueSeparator {
+    /// A normal space.
+    #[default]
+    Space,
+    /// A non-breaking space (U+00A0), which keeps the value and unit on the same line.
+    NonBreaking,
+    /// A thin space (U+2009).
+    Thin,
+    /// A narrow non-breaking space (U+202F), a thin space which keeps the value and unit on the
+    /// same line.
+    NarrowNonBreaking,
+}
+
+impl ValueSeparator {
+    fn as_char(self) -> char {
+        match self {
+            Self::Space => ' ',
+            Self::NonBreaking => '\u{a0}',
+            Self::Thin => '\u{2009}',
+            Self::NarrowNonBreaking => '\u{202f}',
+        }
+    }
+}
+
+/// How powers of units are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Exponents {
+    /// ASCII powers: `s^-2`.
+    #[default]
+    Caret,
+    /// Unicode superscript powers: `s⁻²`.
+    Superscript,
+}
+
+/// How the abbreviations of units are spelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Symbols {
+    /// The usual symbols, which may be Unicode: `μm`, `Ω`, `°`.
+    #[default]
+    Unicode,
+    /// Only ASCII, as given by
+    /// [`UnitInfo::ascii_abbreviation`](crate::UnitInfo::ascii_abbreviation): `um`, `ohm`, `deg`.
+    Ascii,
+}
+
+impl Symbols {
+    fn abbreviation(self, unit: DynUnit) -> &'static str {
+        match self {
+            Self::Unicode => unit.abbreviation(),
+            Self::Ascii => unit.ascii_abbreviation(),
+        }
+    }
+}
+
+/// A [`CompositeUnit`] with a [`UnitFormat`] to write it with, created by
+/// [`CompositeUnit::format`].
+#[derive(Debug)]
+pub struct FormattedUnit<'a, Kind: UnitKind> {
+    unit: &'a CompositeUnit<Kind>,
+    format: UnitFormat,
+}
+
+impl<Kind: UnitKind> CompositeUnit<Kind> {
+    /// Write this unit using the given format.
+    /// ```
+    /// use rus::format::{UnitFormat, UnitStyle};
+    /// use rus::unit_defs as u;
+    /// let unit = u::kg / u::m / u::s;
+    /// let fraction = UnitFormat::new().style(UnitStyle::Fraction);
+    /// assert_eq!(unit.format(fraction).to_string(), "kg / (m s)");
+    /// ```
+    pub fn format(&self, format: UnitFormat) -> FormattedUnit<'_, Kind> {
+        FormattedUnit { unit: self, format }
+    }
+}
+
+impl<Kind: UnitKind> Display for FormattedUnit<'_, Kind> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_unit(f, &self.unit.component_units, self.format)
+    }
+}
+
+/// A [`SingleQuantity`] with a [`UnitFormat`] to write its unit with, created by
+/// [`SingleQuantity::format`].
+///
+/// Formatting options such as precision, width and `{:e}` apply to the value.
+#[derive(Debug)]
+pub struct FormattedQuantity<'a, Kind: UnitKind, S = f32> {
+    quantity: &'a SingleQuantity<Kind, S>,
+    format: UnitFormat,
+}
+
+impl<Kind: UnitKind, S: Scalar> SingleQuantity<Kind, S> {
+    /// Write this quantity with its unit in the given format.
+    ///
+    /// [`UnitStyle::Latex`] writes the whole quantity with `\SI`, and [`UnitStyle::LongName`]
+    /// uses the singular name of the unit when the value is one.
+    /// ```
+    /// use rus::format::{UnitFormat, UnitStyle};
+    /// use rus::unit_defs as u;
+    /// let long = UnitFormat::new().style(UnitStyle::LongName);
+    /// assert_eq!((1. * u::km).format(long).to_string(), "1 kilometer");
+    /// assert_eq!((2. * u::km).format(long).to_string(), "2 kilometers");
+    /// ```
+    pub fn format(&self, format: UnitFormat) -> FormattedQuantity<'_, Kind, S> {
+        FormattedQuantity {
+            quantity: self,
+            format,
+        }
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar> FormattedQuantity<'_, Kind, S> {
+    fn write(
+        &self,
+        f: &mut Formatter<'_>,
+        write_scalar: fn(&S, &mut Formatter<'_>) -> Result,
+    ) -> Result {
+        let SingleQuantity { unit, scalar } = self.quantity;
+        let units = &unit.component_units;
+        if self.format.style == UnitStyle::Latex {
+            f.write_str("\\SI{")?;
+            write_scalar(scalar, f)?;
+            f.write_str("}{")?;
+            write_latex_units(f, units)?;
+            return f.write_char('}');
+        }
+        write_scalar(scalar, f)?;
+        if units.iter().all(|(_, power)| power == 0) {
+            return Ok(());
+        }
+        f.write_char(self.format.value_separator.as_char())?;
+        match self.format.style {
+            UnitStyle::LongName => write_long_names(f, units, scalar.to_f32() != 1.),
+            _ => write_unit(f, units, self.format),
+        }
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar + Display> Display for FormattedQuantity<'_, Kind, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.write(f, Display::fmt)
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar + LowerExp> LowerExp for FormattedQuantity<'_, Kind, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.write(f, LowerExp::fmt)
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar + UpperExp> UpperExp for FormattedQuantity<'_, Kind, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        self.write(f, UpperExp::fmt)
+    }
+}
+
+pub(super) fn write_unit(f: &mut impl Write, units: &UnitList, format: UnitFormat) -> Result {
+    match format.style {
+        UnitStyle::NegativeExponents => {
+            write_product(f, units.iter(), format)?;
+        }
+        UnitStyle::Fraction => {
+            let numerator = units.iter().filter(|(_, power)| *power > 0);
+            let denominator = units
+                .iter()
+                .filter(|(_, power)| *power < 0)
+                .map(|(unit, power)| (unit, -power));
+            let denominator_len = denominator.clone().count();
+            if numerator.clone().next().is_some() {
+                write_product(f, numerator, format)?;
+            } else if denominator_len > 0 {
+                f.write_char('1')?;
+            }
+            match denominator_len {
+                0 => {}
+                1 => {
+                    f.write_str(" / ")?;
+                    write_product(f, denominator, format)?;
+                }
+                _ => {
+                    f.write_str(" / (")?;
+                    write_product(f, denominator, format)?;
+                    f.write_char(')')?;
+                }
+            }
+        }
+        UnitStyle::Latex => {
+            f.write_str("\\si{")?;
+            write_latex_units(f, units)?;
+            f.write_char('}')?;
+        }
+        UnitStyle::LongName => write_long_names(f, units, true)?,
+    }
+    Ok(())
+}
+
+fn write_product(
+    f: &mut impl Write,
+    units: impl Iterator<Item = (DynUnit, i8)>,
+    format: UnitFormat,
+) -> Result {
+    let mut first = true;
+    for (unit, power) in units {
+        if power == 0 {
+            continue;
+        }
+        if !first {
+            f.write_str(format.separator.as_str())?;
+        }
+        first = false;
+        f.write_str(format.symbols.abbreviation(unit))?;
+        match (power, format.exponents) {
+            (1, _) => {}
+            (_, Exponents::Caret) => write!(f, "^{}", power)?,
+            (_, Exponents::Superscript) => write_superscript(f, power)?,
+        }
+    }
+    Ok(())
+}
+
+fn write_superscript(f: &mut impl Write, power: i8) -> Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    if power < 0 {
+        f.write_char('⁻')?;
+    }
+    let power = power.unsigned_abs();
+    // A power has at most three digits.
+    for place in [100, 10, 1] {
+        if power >= place || place == 1 {
+            f.write_char(DIGITS[(power / place % 10) as usize])?;
+        }
+    }
+    Ok(())
+}
+
+/// Prefix names known to `siunitx`.
+const LATEX_PREFIXES: [&str; 24] = [
+    "quetta", "ronna", "yotta", "zetta", "exa", "peta", "tera", "giga", "mega", "kilo", "hecto",
+    "deka", "deci", "centi", "milli", "micro", "nano", "pico", "femto", "atto", "zepto", "yocto",
+    "ronto", "quecto",
+];
+
+/// Unit names and the `siunitx` macros for them: every named SI unit, and the other units which
+/// `siunitx` has a macro for.
+const LATEX_UNITS: [(&str, &str); 48] = [
+    ("meter", "metre"),
+    ("gram", "gram"),
+    ("second", "second"),
+    ("ampere", "ampere"),
+    ("kelvin", "kelvin"),
+    ("mole", "mole"),
+    ("candela", "candela"),
+    ("radian", "radian"),
+    ("steradian", "steradian"),
+    ("hertz", "hertz"),
+    ("newton", "newton"),
+    ("pascal", "pascal"),
+    ("joule", "joule"),
+    ("watt", "watt"),
+    ("coulomb", "coulomb"),
+    ("volt", "volt"),
+    ("farad", "farad"),
+    ("ohm", "ohm"),
+    ("siemens", "siemens"),
+    ("weber", "weber"),
+    ("tesla", "tesla"),
+    ("henry", "henry"),
+    ("degree Celsius", "degreeCelsius"),
+    ("lumen", "lumen"),
+    ("lux", "lux"),
+    ("becquerel", "becquerel"),
+    ("gray", "gray"),
+    ("sievert", "sievert"),
+    ("katal", "katal"),
+    ("minute", "minute"),
+    ("hour", "hour"),
+    ("day", "day"),
+    ("degree", "degree"),
+    ("arcminute", "arcminute"),
+    ("arcsecond", "arcsecond"),
+    ("hectare", "hectare"),
+    ("liter", "litre"),
+    ("tonne", "tonne"),
+    ("astronomical unit", "astronomicalunit"),
+    ("dalton", "dalton"),
+    ("electronvolt", "electronvolt"),
+    ("ångström", "angstrom"),
+    ("bar", "bar"),
+    ("barn", "barn"),
+    ("millimeter of mercury", "mmHg"),
+    ("nautical mile", "nauticalmile"),
+    ("knot", "knot"),
+    ("hartree", "hartree"),
+];
+
+fn write_latex_units(f: &mut impl Write, units: &UnitList) -> Result {
+    for (unit, power) in units.iter().filter(|(_, power)| *power > 0) {
+        write_latex_unit(f, unit, power)?;
+    }
+    for (unit, power) in units.iter().filter(|(_, power)| *power < 0) {
+        f.write_str("\\per")?;
+        write_latex_unit(f, unit, -power)?;
+    }
+    Ok(())
+}
+
+fn write_latex_unit(f: &mut impl Write, unit: DynUnit, power: i8) -> Result {
+    let latex_unit = |name: &str| {
+        LATEX_UNITS
+            .iter()
+            .find(|(unit_name, _)| *unit_name == name)
+            .map(|(_, latex)| *latex)
+    };
+    let prefixed = LATEX_PREFIXES.iter().find_map(|prefix| {
+        let base = unit.name().strip_prefix(prefix)?;
+        Some((*prefix, latex_unit(base)?))
+    });
+    match (latex_unit(unit.name()), prefixed) {
+        (Some(latex), _) => write!(f, "\\{}", latex)?,
+        (None, Some((prefix, latex))) => write!(f, "\\{}\\{}", prefix, latex)?,
+        (None, None) => f.write_str(unit.abbreviation())?,
+    }
+    match power {
+        1 => Ok(()),
+        2 => f.write_str("\\squared"),
+        3 => f.write_str("\\cubed"),
+        _ => write!(f, "\\tothe{{{}}}", power),
+    }
+}
+
+fn write_long_names(f: &mut impl Write, units: &UnitList, plural: bool) -> Result {
+    let numerator = units.iter().filter(|(_, power)| *power > 0);
+    let last = numerator.clone().count().saturating_sub(1);
+    let denominator = units.iter().filter(|(_, power)| *power < 0);
+    let mut first = true;
+    for (i, (unit, power)) in numerator.enumerate() {
+        if !first {
+            f.write_char(' ')?;
+        }
+        first = false;
+        match power {
+            1 => {}
+            2 => f.write_str("square ")?,
+            3 => f.write_str("cubic ")?,
+            _ => {}
+        }
+        match unit.info().plural() {
+            Some(plural_name) if plural && i == last => f.write_str(plural_name)?,
+            _ => {
+                f.write_str(unit.name())?;
+                if plural && i == last && !unit.name().ends_with(['s', 'x', 'z']) {
+                    f.write_char('s')?;
+                }
+            }
+        }
+        if power > 3 {
+            write!(f, " to the power {}", power)?;
+        }
+    }
+    for (unit, power) in denominator {
+        if !first {
+            f.write_char(' ')?;
+        }
+        first = false;
+        write!(f, "per {}", unit.name())?;
+        match -power {
+            1 => {}
+            2 => f.write_str(" squared")?,
+            3 => f.write_str(" cubed")?,
+            power => write!(f, " to the power {}", power)?,
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,129 @@
+//! Units of the British imperial system.
+//!
+//! The units of length and mass are the international yard and pound, which are also used by the
+//! [`us_customary`](crate::us_customary) system. They are defined exactly in terms of SI units.
+//! ```
+//! use rus::imperial;
+//! use rus::unit_defs as u;
+//! let height = 6. * imperial::ft;
+//! assert_eq!(format!("{:.4}", height.to(u::m)), "1.8288 m");
+//! let milk = 1. * imperial::gal;
+//! assert_eq!(format!("{:.2}", milk.to(imperial::fl_oz)), "160.00 imp fl oz");
+//! ```
+
+use crate::kinds::*;
+use crate::unit_defs::{dm, kg, m, s, J, K};
+use crate::DynUnit;
+use rus_macros::create_unit;
+
+create_unit!(
+    /// An international inch.
+    inch: LengthUnit = 0.0254 * m,
+    "inch" "in",
+    plural = "inches"
+);
+create_unit!(
+    /// An international foot, twelve inches.
+    ft: LengthUnit = 12 * inch,
+    "foot" "ft",
+    plural = "feet",
+    aliases = [foot]
+);
+create_unit!(
+    /// An international yard, three feet.
+    yd: LengthUnit = 3 * ft,
+    "yard" "yd",
+    aliases = [yard]
+);
+create_unit!(
+    /// An international mile, 1760 yards.
+    mi: LengthUnit = 1760 * yd,
+    "mile" "mi",
+    aliases = [mile]
+);
+
+create_unit!(
+    /// An international avoirdupois pound.
+    lb: MassUnit = 0.453_592_37 * kg,
+    "pound" "lb",
+    aliases = [pound]
+);
+create_unit!(
+    /// An avoirdupois ounce, one sixteenth of a pound.
+    oz: MassUnit = lb / 16,
+    "ounce" "oz",
+    aliases = [ounce]
+);
+create_unit!(
+    /// A pound-force. The weight of a pound under standard gravity.
+    lbf: ForceUnit = 9.806_65 * lb * m / s^2,
+    "pound-force" "lbf",
+    plural = "pounds-force"
+);
+
+create_unit!(
+    /// An imperial gallon.
+    gal: VolumeUnit = 4.546_09 * dm^3,
+    "imperial gallon" "imp gal",
+    aliases = [gallon]
+);
+create_unit!(
+    /// An imperial fluid ounce, one 160th of an imperial gallon.
+    fl_oz: VolumeUnit = gal / 160,
+    "imperial fluid ounce" "imp fl oz"
+);
+
+create_unit!(
+    /// A pound-force per square inch.
+    psi: PressureUnit = lbf / inch^2,
+    "pound per square inch" "psi",
+    plural = "pounds per square inch"
+);
+create_unit!(
+    /// An international table British thermal unit, the energy to warm a pound of water by one
+    /// degree Fahrenheit with an international table calorie of 4.1868 joules per gram kelvin.
+    BTU: EnergyUnit = 4.1868 * 453.592_37 / 1.8 * J,
+    "British thermal unit" "BTU"
+);
+create_unit!(
+    /// A mechanical horsepower, 550 foot pound-force per second.
+    hp: PowerUnit = 550 * ft * lbf / s,
+    "horsepower" "hp",
+    plural = "horsepower"
+);
+create_unit!(
+    /// A Fahrenheit degree, five ninths of a kelvin. This is a temperature difference, not a
+    /// temperature on the Fahrenheit scale.
+    ///
+    /// Units are only scaled, never offset, so converting a reading such as 32 °F with this unit
+    /// would give 17.8 K rather than 273.15 K. The offset of the Fahrenheit scale must be applied
+    /// separately.
+    /// ```
+    /// use rus::imperial::delta_degF;
+    /// use rus::unit_defs as u;
+    /// let warming = 9. * delta_degF;
+    /// assert_eq!(format!("{:.0}", warming.to(u::K)), "5 K");
+    /// assert_eq!(warming.to_string(), "9 Δ°F");
+    /// ```
+    delta_degF: TemperatureUnit = 5 / 9 * K,
+    "Fahrenheit degree" "Δ°F",
+    ascii = "delta_degF"
+);
+
+/// The imperial units, for looking units up by name with [`DynUnit::find`] and
+/// [`CompositeUnit::parse`](crate::CompositeUnit::parse).
+pub const UNITS: &[DynUnit] = &[
+    inch.to_dyn(),
+    ft.to_dyn(),
+    yd.to_dyn(),
+    mi.to_dyn(),
+    lb.to_dyn(),
+    oz.to_dyn(),
+    lbf.to_dyn(),
+    gal.to_dyn(),
+    fl_oz.to_dyn(),
+    psi.to_dyn(),
+    BTU.to_dyn(),
+    hp.to_dyn(),
+    delta_degF.to_dyn(),
+];
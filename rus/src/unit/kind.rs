@@ -1,12 +1,19 @@
-use rus_macros::type_arith;
-use std::{
+//! The kinds (dimensions) of units.
+//!
+//! A kind is tracked in the type system as a [`CompositeUnitKind`], whose parameters are the
+//! [`typenum`] powers of each SI base dimension. New kinds can be built from existing ones with
+//! [`type_arith!`](crate::type_arith).
+
+use core::{
     marker::PhantomData,
     ops::{Add, Div, Mul, Sub},
 };
-use typenum::{tarr, Diff, Integer, Sum, P1, Z0};
+use rus_macros::type_arith;
+use typenum::{tarr, Diff, Integer, Pow, Prod, Sum, P1, Z0};
 
 use super::composite::IntoComp;
 
+/// The runtime equivalent of a [`CompositeUnitKind`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct DynKind {
     length: i8,
@@ -18,10 +25,121 @@ pub struct DynKind {
     luminosiy: i8,
 }
 
+impl DynKind {
+    /// Whether this is the kind of a dimensionless value.
+    pub fn is_dimensionless(self) -> bool {
+        self == Self::default()
+    }
+
+    /// The powers of each SI base dimension, in the order length, mass, time, current,
+    /// temperature, amount and luminosity.
+    pub const fn powers(self) -> [i8; 7] {
+        [
+            self.length,
+            self.mass,
+            self.time,
+            self.current,
+            self.temperature,
+            self.amount,
+            self.luminosiy,
+        ]
+    }
+
+    /// Raise this kind to a power.
+    pub fn powi(self, power: i8) -> Self {
+        Self {
+            length: self.length * power,
+            mass: self.mass * power,
+            time: self.time * power,
+            current: self.current * power,
+            temperature: self.temperature * power,
+            amount: self.amount * power,
+            luminosiy: self.luminosiy * power,
+        }
+    }
+}
+
+impl Mul for DynKind {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            length: self.length + rhs.length,
+            mass: self.mass + rhs.mass,
+            time: self.time + rhs.time,
+            current: self.current + rhs.current,
+            temperature: self.temperature + rhs.temperature,
+            amount: self.amount + rhs.amount,
+            luminosiy: self.luminosiy + rhs.luminosiy,
+        }
+    }
+}
+
+/// Implemented by all types which represent the kind of a unit.
 pub trait UnitKind {
-    fn to_dynkind() -> DynKind;
+    /// The runtime representation of this kind.
+    const DYN_KIND: DynKind;
+
+    /// Get the runtime representation of this kind.
+    fn to_dynkind() -> DynKind {
+        Self::DYN_KIND
+    }
+}
+
+/// Implemented for a kind by every kind which is equal to it.
+///
+/// Used to check that the initializer of a unit definition has the declared kind.
+pub trait SameKind<K> {}
+
+impl<L1, M1, T1, C1, Te1, A1, Lu1, L2, M2, T2, C2, Te2, A2, Lu2>
+    SameKind<CompositeUnitKind<L2, M2, T2, C2, Te2, A2, Lu2>>
+    for CompositeUnitKind<L1, M1, T1, C1, Te1, A1, Lu1>
+where
+    L1: SameDimension<L2, dimension::Length>,
+    M1: SameDimension<M2, dimension::Mass>,
+    T1: SameDimension<T2, dimension::Time>,
+    C1: SameDimension<C2, dimension::Current>,
+    Te1: SameDimension<Te2, dimension::Temperature>,
+    A1: SameDimension<A2, dimension::Amount>,
+    Lu1: SameDimension<Lu2, dimension::Luminosity>,
+{
+}
+
+/// Implemented for the power of a dimension by the same power, where `Dim` names the dimension.
+#[diagnostic::on_unimplemented(
+    message = "mismatched unit kinds: the power of {Dim} differs from the declared unit type",
+    label = "expected the power of {Dim} to be `{Expected}`, found `{Self}`"
+)]
+pub trait SameDimension<Expected, Dim> {}
+
+impl<Power, Dim> SameDimension<Power, Dim> for Power {}
+
+/// Markers naming each SI base dimension in compile errors.
+pub mod dimension {
+    /// The length dimension.
+    #[derive(Debug)]
+    pub struct Length;
+    /// The mass dimension.
+    #[derive(Debug)]
+    pub struct Mass;
+    /// The time dimension.
+    #[derive(Debug)]
+    pub struct Time;
+    /// The electric current dimension.
+    #[derive(Debug)]
+    pub struct Current;
+    /// The temperature dimension.
+    #[derive(Debug)]
+    pub struct Temperature;
+    /// The amount of substance dimension.
+    #[derive(Debug)]
+    pub struct Amount;
+    /// The luminous intensity dimension.
+    #[derive(Debug)]
+    pub struct Luminosity;
 }
 
+/// The kind of a unit, as powers of each of the SI base dimensions.
 pub struct CompositeUnitKind<Length, Mass, Time, Current, Temperature, Amount, Luminosity> {
     #[allow(clippy::type_complexity)]
     _marker: PhantomData<tarr![Length, Mass, Time, Current, Temperature, Amount, Luminosity]>,
@@ -35,8 +153,8 @@ impl<L, M, T, C, Te, A, Lu> Clone for CompositeUnitKind<L, M, T, C, Te, A, Lu> {
     }
 }
 
-impl<L, M, T, C, Te, A, Lu> std::fmt::Debug for CompositeUnitKind<L, M, T, C, Te, A, Lu> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<L, M, T, C, Te, A, Lu> core::fmt::Debug for CompositeUnitKind<L, M, T, C, Te, A, Lu> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "CompositeUnitKind")
     }
 }
@@ -58,17 +176,15 @@ impl<L, M, T, C, Te, A, Lu> PartialEq for CompositeUnitKind<L, M, T, C, Te, A, L
 impl<L: Integer, M: Integer, T: Integer, C: Integer, Te: Integer, A: Integer, Lu: Integer> UnitKind
     for CompositeUnitKind<L, M, T, C, Te, A, Lu>
 {
-    fn to_dynkind() -> DynKind {
-        DynKind {
-            length: L::to_i8(),
-            mass: M::to_i8(),
-            time: T::to_i8(),
-            current: C::to_i8(),
-            temperature: Te::to_i8(),
-            amount: A::to_i8(),
-            luminosiy: Lu::to_i8(),
-        }
-    }
+    const DYN_KIND: DynKind = DynKind {
+        length: L::I8,
+        mass: M::I8,
+        time: T::I8,
+        current: C::I8,
+        temperature: Te::I8,
+        amount: A::I8,
+        luminosiy: Lu::I8,
+    };
 }
 
 impl<L1, M1, T1, C1, Te1, A1, Lu1, L2, M2, T2, C2, Te2, A2, Lu2>
@@ -179,12 +295,82 @@ where
     }
 }
 
+impl<L, M, T, C, Te, A, Lu, N: Integer> Pow<N> for CompositeUnitKind<L, M, T, C, Te, A, Lu>
+where
+    L: Mul<N>,
+    M: Mul<N>,
+    T: Mul<N>,
+    C: Mul<N>,
+    Te: Mul<N>,
+    A: Mul<N>,
+    Lu: Mul<N>,
+{
+    type Output = CompositeUnitKind<
+        Prod<L, N>,
+        Prod<M, N>,
+        Prod<T, N>,
+        Prod<C, N>,
+        Prod<Te, N>,
+        Prod<A, N>,
+        Prod<Lu, N>,
+    >;
+
+    fn powi(self, _: N) -> Self::Output {
+        Self::Output::default()
+    }
+}
+
+/// The kind of a dimensionless value, such as a ratio.
+pub type DimensionlessKind = CompositeUnitKind<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// The kind of a length.
 pub type LengthKind = CompositeUnitKind<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// The kind of a mass.
 pub type MassKind = CompositeUnitKind<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+/// The kind of a time.
 pub type TimeKind = CompositeUnitKind<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+/// The kind of an electric current.
 pub type CurrentKind = CompositeUnitKind<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+/// The kind of a temperature.
 pub type TemperatureKind = CompositeUnitKind<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+/// The kind of an amount of substance.
 pub type AmountKind = CompositeUnitKind<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+/// The kind of a luminous intensity.
 pub type LuminosityKind = CompositeUnitKind<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
 
-pub type ForceKind = type_arith!(LengthKind * MassKind / TimeKind / TimeKind);
+/// The kind of a plane angle. Angles are dimensionless.
+pub type AngleKind = DimensionlessKind;
+
+/// The kind of an area.
+pub type AreaKind = type_arith!(LengthKind ^ 2);
+/// The kind of a volume.
+pub type VolumeKind = type_arith!(LengthKind ^ 3);
+
+/// The kind of a velocity.
+pub type VelocityKind = type_arith!(LengthKind / TimeKind);
+/// The kind of a frequency.
+pub type FrequencyKind = type_arith!(TimeKind ^ -1);
+/// The kind of a force.
+pub type ForceKind = type_arith!(LengthKind * MassKind / TimeKind ^ 2);
+/// The kind of an energy.
+pub type EnergyKind = type_arith!(ForceKind * LengthKind);
+/// The kind of a power.
+pub type PowerKind = type_arith!(EnergyKind / TimeKind);
+/// The kind of an electric potential difference, or voltage.
+pub type VoltageKind = type_arith!(PowerKind / CurrentKind);
+/// The kind of an electrical resistance.
+pub type ResistanceKind = type_arith!(MassKind * LengthKind ^ 2 / (TimeKind ^ 3 * CurrentKind ^ 2));
+/// The kind of a pressure.
+pub type PressureKind = type_arith!(ForceKind / AreaKind);
+/// The kind of an electric charge.
+pub type ChargeKind = type_arith!(CurrentKind * TimeKind);
+/// The kind of a magnetic flux density.
+pub type MagneticFluxDensityKind = type_arith!(MassKind / (TimeKind ^ 2 * CurrentKind));
+/// The kind of a dynamic viscosity.
+pub type DynamicViscosityKind = type_arith!(PressureKind * TimeKind);
+/// The kind of a kinematic viscosity.
+pub type KinematicViscosityKind = type_arith!(AreaKind / TimeKind);
+/// The kind of a spectral flux density, a power per unit area per unit frequency.
+pub type SpectralFluxDensityKind = type_arith!(PowerKind / (AreaKind * FrequencyKind));
+/// The kind of a photon radiance, counted in photons per unit time per unit area per steradian.
+/// Photons and steradians are dimensionless.
+pub type PhotonRadianceKind = type_arith!(FrequencyKind / AreaKind);
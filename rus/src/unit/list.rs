@@ -0,0 +1,184 @@
+use super::kind::{DimensionlessKind, UnitKind};
+use super::{DynUnit, UnitInfo};
+use core::fmt::{Debug, Formatter, Result};
+
+/// The most distinct units which a [`CompositeUnit`](super::CompositeUnit) can be made of.
+pub(crate) const CAPACITY: usize = 16;
+
+/// Fills the unused slots of a [`UnitList`].
+const EMPTY: DynUnit = DynUnit {
+    info: &UnitInfo {
+        kind: DimensionlessKind::DYN_KIND,
+        scale: 1.,
+        exact_scale: None,
+        abbreviation: "",
+        ascii_abbreviation: "",
+        abbreviations: &[],
+        aliases: &[],
+        name: "",
+        plural: None,
+        prefixes: &[],
+    },
+};
+
+/// The units of a composite unit with their powers, stored inline so that composite units are
+/// `Copy` and never allocate.
+///
+/// The units and powers are kept in separate arrays, so that the powers do not pad out each unit.
+#[derive(Clone, Copy)]
+pub(crate) struct UnitList {
+    units: [DynUnit; CAPACITY],
+    powers: [i8; CAPACITY],
+    len: u8,
+}
+
+impl UnitList {
+    /// An empty list.
+    pub(crate) const fn new() -> Self {
+        Self {
+            units: [EMPTY; CAPACITY],
+            powers: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    /// The number of units in the list.
+    pub(crate) fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// The units in the list.
+    pub(crate) fn units(&self) -> &[DynUnit] {
+        &self.units[..self.len()]
+    }
+
+    /// The units in the list, which may be replaced by units of the same kind.
+    pub(crate) fn units_mut(&mut self) -> &mut [DynUnit] {
+        let len = self.len();
+        &mut self.units[..len]
+    }
+
+    /// The power of each unit in the list.
+    pub(crate) fn powers(&self) -> &[i8] {
+        &self.powers[..self.len()]
+    }
+
+    /// The power of each unit in the list, which may be changed.
+    pub(crate) fn powers_mut(&mut self) -> &mut [i8] {
+        let len = self.len();
+        &mut self.powers[..len]
+    }
+
+    /// The units in the list with their powers.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (DynUnit, i8)> + Clone + '_ {
+        self.units()
+            .iter()
+            .copied()
+            .zip(self.powers().iter().copied())
+    }
+
+    /// The units in the list with their powers, which may be changed.
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (DynUnit, &mut i8)> {
+        let len = self.len();
+        self.units[..len]
+            .iter()
+            .copied()
+            .zip(&mut self.powers[..len])
+    }
+
+    /// Add a unit to the end of the list.
+    ///
+    /// # Panics
+    /// Panics if the list already holds [`CAPACITY`] units.
+    pub(crate) fn push(&mut self, unit: (DynUnit, i8)) {
+        self.insert(self.len(), unit);
+    }
+
+    /// Add a unit to the end of a list which has room for it, in a constant expression.
+    pub(crate) const fn with(mut self, unit: DynUnit, power: i8) -> Self {
+        self.units[self.len as usize] = unit;
+        self.powers[self.len as usize] = power;
+        self.len += 1;
+        self
+    }
+
+    /// Insert a unit at `index`, shifting the units after it along.
+    ///
+    /// # Panics
+    /// Panics if the list already holds [`CAPACITY`] units.
+    pub(crate) fn insert(&mut self, index: usize, unit: (DynUnit, i8)) {
+        if self.try_insert(index, unit).is_none() {
+            capacity_exceeded();
+        }
+    }
+
+    /// Add a unit to the end of the list, or return `None` if the list is full.
+    pub(crate) fn try_push(&mut self, unit: (DynUnit, i8)) -> Option<()> {
+        self.try_insert(self.len(), unit)
+    }
+
+    /// Insert a unit at `index`, shifting the units after it along, or return `None` if the list
+    /// is full.
+    pub(crate) fn try_insert(&mut self, index: usize, (unit, power): (DynUnit, i8)) -> Option<()> {
+        let len = self.len();
+        if len == CAPACITY {
+            return None;
+        }
+        self.units[index..=len].rotate_right(1);
+        self.powers[index..=len].rotate_right(1);
+        self.units[index] = unit;
+        self.powers[index] = power;
+        self.len += 1;
+        Some(())
+    }
+
+    /// Keep only the units for which `keep` returns true, in their original order.
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(DynUnit, i8) -> bool) {
+        let mut kept = 0;
+        for index in 0..self.len() {
+            if keep(self.units[index], self.powers[index]) {
+                self.units.swap(kept, index);
+                self.powers.swap(kept, index);
+                kept += 1;
+            }
+        }
+        self.units[kept..].fill(EMPTY);
+        self.powers[kept..].fill(0);
+        self.len = kept as u8;
+    }
+}
+
+/// Panic because a composite unit would need more than [`CAPACITY`] distinct units.
+#[cold]
+#[track_caller]
+pub(crate) fn capacity_exceeded() -> ! {
+    panic!("a composite unit can be made of at most {CAPACITY} distinct units")
+}
+
+impl PartialEq for UnitList {
+    fn eq(&self, other: &Self) -> bool {
+        self.units() == other.units() && self.powers() == other.powers()
+    }
+}
+
+impl Debug for UnitList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<(DynUnit, i8)> for UnitList {
+    fn from_iter<I: IntoIterator<Item = (DynUnit, i8)>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for unit in iter {
+            list.push(unit);
+        }
+        list
+    }
+}
+
+impl<const N: usize> From<[(DynUnit, i8); N]> for UnitList {
+    fn from(units: [(DynUnit, i8); N]) -> Self {
+        units.into_iter().collect()
+    }
+}
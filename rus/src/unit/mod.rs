@@ -1,31 +1,273 @@
+pub mod cgs;
 pub mod composite;
 pub use composite::CompositeUnit;
+pub mod format;
+pub mod imperial;
 pub mod kind;
+mod list;
+mod natural;
+mod parse;
 use kind::*;
+pub use parse::ParseUnitError;
+pub mod prefix;
+use prefix::PrefixedUnit;
+mod ratio;
+pub use ratio::Ratio;
+mod simplify;
 pub mod single;
 pub use single::SingleUnit;
+pub mod us_customary;
 // pub mod equivalency;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-struct DynUnit {
+/// The description of a single unit: its kind, scale, abbreviation and name.
+///
+/// Units only hold a reference to their description, which keeps them and the composite units
+/// made from them small. Descriptions are created with [`SingleUnit::info`], usually by
+/// [`create_unit!`](crate::create_unit).
+#[derive(Clone, Copy)]
+pub struct UnitInfo {
     kind: DynKind,
     scale: f32,
+    exact_scale: Option<Ratio>,
     abbreviation: &'static str,
+    ascii_abbreviation: &'static str,
+    abbreviations: &'static [&'static str],
+    aliases: &'static [&'static str],
     name: &'static str,
+    plural: Option<&'static str>,
+    prefixes: &'static [PrefixedUnit],
 }
 
-impl<Kind: UnitKind> From<SingleUnit<Kind>> for DynUnit {
-    fn from(other: SingleUnit<Kind>) -> Self {
-        Self {
-            kind: Kind::to_dynkind(),
-            scale: other.scale,
-            abbreviation: other.abbreviation,
-            name: other.name,
-        }
+impl UnitInfo {
+    /// Set the exact scale from the SI base unit equivalent, if it is known. This is set by
+    /// [`create_unit!`](crate::create_unit) when the scale is made of decimal numbers and units
+    /// with exact scales.
+    pub const fn with_exact_scale(mut self, exact_scale: Option<Ratio>) -> Self {
+        self.exact_scale = exact_scale;
+        self
+    }
+
+    /// Set the spelling of the abbreviation using only ASCII, such as `um` for `μm`. This defaults
+    /// to the abbreviation, and is set by [`create_unit!`](crate::create_unit) when given with
+    /// `ascii = "..."`.
+    pub const fn with_ascii_abbreviation(mut self, ascii_abbreviation: &'static str) -> Self {
+        self.ascii_abbreviation = ascii_abbreviation;
+        self
+    }
+
+    /// Set the other spellings of the abbreviation which are accepted for this unit, such as `l`
+    /// for `L`. This is set by [`create_unit!`](crate::create_unit) when given with
+    /// `abbreviations = [...]`, which also adds the other spellings of prefixes, such as the micro
+    /// sign `µ` for `μ`.
+    pub const fn with_abbreviations(mut self, abbreviations: &'static [&'static str]) -> Self {
+        self.abbreviations = abbreviations;
+        self
+    }
+
+    /// Set the other identifiers which this unit is defined under, such as `candela` for `cd`.
+    /// This is set by [`create_unit!`](crate::create_unit) when given with `aliases = [...]`.
+    pub const fn with_aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Set the plural of the name, for names which do not take an `s`, such as `feet` for `foot`.
+    /// This is set by [`create_unit!`](crate::create_unit) when given with `plural = "..."`.
+    pub const fn with_plural(mut self, plural: &'static str) -> Self {
+        self.plural = Some(plural);
+        self
+    }
+
+    /// Set the family of prefixed units which this unit belongs to, used by
+    /// [`SingleQuantity::to_best_prefix`](crate::SingleQuantity::to_best_prefix). This is set by
+    /// [`create_unit_with_prefixes!`](crate::create_unit_with_prefixes).
+    pub const fn with_prefixes(mut self, prefixes: &'static [PrefixedUnit]) -> Self {
+        self.prefixes = prefixes;
+        self
+    }
+
+    /// The kind of this unit.
+    pub const fn kind(&self) -> DynKind {
+        self.kind
+    }
+
+    /// The scale from the SI base unit equivalent.
+    pub const fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    /// The exact scale from the SI base unit equivalent, if it is known.
+    pub const fn exact_scale(&self) -> Option<Ratio> {
+        self.exact_scale
+    }
+
+    /// An abbreviation for the unit.
+    pub const fn abbreviation(&self) -> &'static str {
+        self.abbreviation
+    }
+
+    /// The abbreviation written using only ASCII, used by
+    /// [`Symbols::Ascii`](format::Symbols::Ascii).
+    pub const fn ascii_abbreviation(&self) -> &'static str {
+        self.ascii_abbreviation
+    }
+
+    /// The other spellings of the abbreviation which are accepted for this unit.
+    pub const fn abbreviations(&self) -> &'static [&'static str] {
+        self.abbreviations
+    }
+
+    /// The other identifiers which this unit is defined under.
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// The full name of the unit.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The plural of the name, if it is not made by adding an `s`.
+    pub const fn plural(&self) -> Option<&'static str> {
+        self.plural
+    }
+
+    /// Whether `abbreviation` is the abbreviation of this unit, its ASCII spelling or one of its
+    /// other accepted spellings.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let info = u::uL.to_dyn().info();
+    /// assert_eq!(info.abbreviation(), "μL");
+    /// assert!(info.has_abbreviation("uL"));
+    /// assert!(info.has_abbreviation("\u{b5}l"));
+    /// assert!(!info.has_abbreviation("mL"));
+    /// ```
+    pub fn has_abbreviation(&self, abbreviation: &str) -> bool {
+        self.abbreviation == abbreviation
+            || self.ascii_abbreviation == abbreviation
+            || self.abbreviations.contains(&abbreviation)
+    }
+
+    /// The family of prefixed units which this unit belongs to. This is empty if the unit was not
+    /// created with [`create_unit_with_prefixes!`](crate::create_unit_with_prefixes).
+    pub const fn prefixes(&self) -> &'static [PrefixedUnit] {
+        self.prefixes
     }
 }
 
-trait UnitFmt {
-    fn abbrevation() -> &'static str;
-    fn name() -> &'static str;
+/// Units are equal when they have the same kind, scale, abbreviation and name. The prefix family
+/// is not compared, since it follows from the rest, and comparing it would make every comparison
+/// of units walk a table of prefixed units.
+impl PartialEq for UnitInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.scale == other.scale
+            && self.abbreviation == other.abbreviation
+            && self.name == other.name
+    }
+}
+
+impl core::fmt::Debug for UnitInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UnitInfo")
+            .field("kind", &self.kind)
+            .field("scale", &self.scale)
+            .field("abbreviation", &self.abbreviation)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// A single unit whose kind is only known at runtime.
+///
+/// Created from a [`SingleUnit`] with [`SingleUnit::to_dyn`], for example to make lists of units
+/// of different kinds. This is a reference to the [`UnitInfo`] of the unit, so it is the size of a
+/// pointer.
+#[derive(Clone, Copy)]
+pub struct DynUnit {
+    info: &'static UnitInfo,
+}
+
+impl DynUnit {
+    /// The description of this unit.
+    pub const fn info(self) -> &'static UnitInfo {
+        self.info
+    }
+
+    /// The kind of this unit.
+    pub const fn kind(self) -> DynKind {
+        self.info.kind
+    }
+
+    /// The scale from the SI base unit equivalent.
+    pub const fn scale(self) -> f32 {
+        self.info.scale
+    }
+
+    /// The exact scale from the SI base unit equivalent, if it is known.
+    pub const fn exact_scale(self) -> Option<Ratio> {
+        self.info.exact_scale
+    }
+
+    /// An abbreviation for the unit.
+    pub const fn abbreviation(self) -> &'static str {
+        self.info.abbreviation
+    }
+
+    /// The abbreviation written using only ASCII.
+    pub const fn ascii_abbreviation(self) -> &'static str {
+        self.info.ascii_abbreviation
+    }
+
+    /// The full name of the unit.
+    pub const fn name(self) -> &'static str {
+        self.info.name
+    }
+
+    /// Find the unit which `symbol` names among `units` and their prefixed units, by any of its
+    /// abbreviations or aliases.
+    ///
+    /// The units themselves are searched before their prefixed units.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// use rus::DynUnit;
+    /// assert_eq!(DynUnit::find("cd", u::UNITS), Some(u::cd.to_dyn()));
+    /// assert_eq!(DynUnit::find("candela", u::UNITS), Some(u::cd.to_dyn()));
+    /// assert_eq!(DynUnit::find("um", u::UNITS), Some(u::um.to_dyn()));
+    /// assert_eq!(DynUnit::find("kmol", u::UNITS), Some(u::kmol.to_dyn()));
+    /// assert_eq!(DynUnit::find("kcandela", u::UNITS), None);
+    /// ```
+    pub fn find(symbol: &str, units: &[DynUnit]) -> Option<Self> {
+        let prefixed = units
+            .iter()
+            .flat_map(|unit| unit.info.prefixes)
+            .map(|prefixed| Self {
+                info: prefixed.info,
+            });
+        units
+            .iter()
+            .copied()
+            .chain(prefixed)
+            .find(|unit| unit.info.has_abbreviation(symbol) || unit.info.aliases.contains(&symbol))
+    }
+}
+
+/// Compared in the same way as a [`UnitInfo`]. Units which share a description are equal without
+/// comparing it.
+impl PartialEq for DynUnit {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self.info, other.info) || self.info == other.info
+    }
+}
+
+impl core::fmt::Debug for DynUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.info, f)
+    }
+}
+
+impl<Kind: UnitKind> From<SingleUnit<Kind>> for DynUnit {
+    fn from(other: SingleUnit<Kind>) -> Self {
+        other.to_dyn()
+    }
 }
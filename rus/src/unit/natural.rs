@@ -0,0 +1,64 @@
+use super::{CompositeUnit, DynKind, UnitKind};
+use crate::constants;
+use crate::quantity::SingleQuantity;
+use crate::scalar::Scalar;
+
+impl<Kind: UnitKind, S: Scalar> SingleQuantity<Kind, S> {
+    /// Convert this quantity to a unit of a different kind using natural units, where the speed of
+    /// light and the reduced Planck constant are both one.
+    ///
+    /// In natural units energy, mass, inverse length and inverse time are interchangeable, so a
+    /// mass can be given in GeV or an energy in inverse seconds. The powers of `c` and `ħ` to
+    /// apply are worked out from the kinds of this quantity and the target unit. Returns `None` if
+    /// no powers of `c` and `ħ` relate the two kinds, such as for a current and a length.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let proton = 0.938 * u::GeV;
+    /// let mass = proton.to_natural(u::kg).unwrap();
+    /// assert_eq!(format!("{:.3e}", mass), "1.672e-27 kg");
+    /// let frequency = (1. * u::MeV).to_natural(u::Hz).unwrap();
+    /// assert_eq!(format!("{:.3e}", frequency), "1.519e21 Hz");
+    /// assert!((1. * u::A).to_natural(u::m).is_none());
+    /// ```
+    pub fn to_natural<Target: UnitKind>(
+        &self,
+        unit: impl Into<CompositeUnit<Target>>,
+    ) -> Option<SingleQuantity<Target, S>> {
+        let unit = unit.into();
+        let (c_power, hbar_power) = natural_powers(Kind::DYN_KIND, Target::DYN_KIND)?;
+        // The constants are in SI base units, as are the scale factors.
+        let speed_of_light = *constants::c.scalar();
+        let reduced_planck = *constants::hbar.scalar();
+        // Apply one factor at a time, since powers of ħ quickly fall outside the range of f32.
+        let mut factor = self.unit.scale_factor();
+        for _ in 0..c_power.unsigned_abs() {
+            if c_power > 0 {
+                factor *= speed_of_light;
+            } else {
+                factor /= speed_of_light;
+            }
+        }
+        for _ in 0..hbar_power.unsigned_abs() {
+            if hbar_power > 0 {
+                factor *= reduced_planck;
+            } else {
+                factor /= reduced_planck;
+            }
+        }
+        let factor = factor / unit.scale_factor();
+        Some(SingleQuantity::new(unit, self.scalar.clone().scale(factor)))
+    }
+}
+
+/// Find the powers of `c` and `ħ` which turn a quantity of kind `from` into one of kind `to`.
+fn natural_powers(from: DynKind, to: DynKind) -> Option<(i8, i8)> {
+    let [from_length, from_mass, from_time, from_rest @ ..] = from.powers();
+    let [to_length, to_mass, to_time, to_rest @ ..] = to.powers();
+    if from_rest != to_rest {
+        return None;
+    }
+    // `c` is m s^-1 and `ħ` is kg m^2 s^-1, so only `ħ` changes the power of mass.
+    let hbar_power = to_mass - from_mass;
+    let c_power = to_length - from_length - 2 * hbar_power;
+    (to_time - from_time == -c_power - hbar_power).then_some((c_power, hbar_power))
+}
@@ -0,0 +1,179 @@
+use super::list::{UnitList, CAPACITY};
+use super::{CompositeUnit, DynKind, DynUnit, UnitKind};
+use crate::unit_defs::UNITS;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+/// The reason a unit could not be parsed by [`CompositeUnit::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseUnitError {
+    /// A symbol is not an abbreviation or alias of any of the units searched.
+    UnknownUnit,
+    /// A power is not an integer which fits in an `i8`.
+    InvalidPower,
+    /// The unit is not of the kind it was parsed as.
+    WrongKind,
+    /// The unit is made of more distinct units than a composite unit can hold.
+    TooManyUnits,
+}
+
+impl Display for ParseUnitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownUnit => f.write_str("unknown unit"),
+            Self::InvalidPower => f.write_str("invalid power"),
+            Self::WrongKind => f.write_str("the unit is of the wrong kind"),
+            Self::TooManyUnits => write!(
+                f,
+                "a composite unit can be made of at most {CAPACITY} distinct units"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseUnitError {}
+
+impl<Kind: UnitKind> CompositeUnit<Kind> {
+    /// Read a unit written as [`Display`] writes it, looking its units up in `units` with
+    /// [`DynUnit::find`].
+    ///
+    /// Units are separated by whitespace, `·` or `*`, and each may be raised to a power written as
+    /// `^-2` or `⁻²`. Every unit after a ` / ` is divided by. Units may be written by any of their
+    /// abbreviations or aliases.
+    ///
+    /// # Errors
+    /// Returns a [`ParseUnitError`] if a unit is not found, a power is not an integer, the unit is
+    /// of a different kind, or it is made of too many distinct units.
+    /// ```
+    /// use rus::kind::VelocityKind;
+    /// use rus::unit_defs as u;
+    /// use rus::{CompositeUnit, ParseUnitError};
+    /// let speed = CompositeUnit::<VelocityKind>::parse;
+    /// assert_eq!(speed("km s^-1", u::UNITS), Ok(u::km / u::s));
+    /// assert_eq!(speed("km / hour", u::UNITS), Ok(u::km / u::h));
+    /// assert_eq!(speed("m·s⁻¹", u::UNITS), Ok(u::m / u::s));
+    /// assert_eq!(speed("kg", u::UNITS), Err(ParseUnitError::WrongKind));
+    /// assert_eq!(speed("furlong / fortnight", u::UNITS), Err(ParseUnitError::UnknownUnit));
+    /// ```
+    pub fn parse(text: &str, units: &[DynUnit]) -> Result<Self, ParseUnitError> {
+        let mut list = UnitList::new();
+        let mut kind = DynKind::default();
+        let mut sign = 1;
+        let mut words = text
+            .split(|c: char| c.is_whitespace() || c == '·' || c == '*')
+            .filter(|word| !word.is_empty());
+        while let Some(word) = words.next() {
+            if word == "/" {
+                sign = -1;
+                continue;
+            }
+            let (unit, power) = match find_factor(word, units) {
+                Ok(factor) => factor,
+                // Abbreviations such as `imp fl oz` are written with spaces.
+                Err(error) => {
+                    let start = offset(text, word);
+                    let joined = words.clone().enumerate().find_map(|(count, next)| {
+                        let end = offset(text, next) + next.len();
+                        let factor = find_factor(&text[start..end], units).ok()?;
+                        Some((count, factor))
+                    });
+                    let (count, factor) = joined.ok_or(error)?;
+                    words.nth(count);
+                    factor
+                }
+            };
+            let power = power
+                .checked_mul(sign)
+                .ok_or(ParseUnitError::InvalidPower)?;
+            kind = kind * unit.kind().powi(power);
+            match list.units().iter().position(|other| *other == unit) {
+                Some(index) => {
+                    let total = &mut list.powers_mut()[index];
+                    *total = total
+                        .checked_add(power)
+                        .ok_or(ParseUnitError::InvalidPower)?;
+                }
+                None => list
+                    .try_push((unit, power))
+                    .ok_or(ParseUnitError::TooManyUnits)?,
+            }
+        }
+        list.retain(|_, power| power != 0);
+        if kind != Kind::DYN_KIND {
+            return Err(ParseUnitError::WrongKind);
+        }
+        Ok(Self::new(list))
+    }
+}
+
+/// Parses units from [`unit_defs`](crate::unit_defs), as [`CompositeUnit::parse`] does with
+/// [`unit_defs::UNITS`](crate::unit_defs::UNITS).
+/// ```
+/// use rus::kind::LengthKind;
+/// use rus::unit_defs as u;
+/// use rus::CompositeUnit;
+/// let unit: CompositeUnit<LengthKind> = "nmi".parse().unwrap();
+/// assert_eq!(unit, u::nmi.into());
+/// ```
+impl<Kind: UnitKind> FromStr for CompositeUnit<Kind> {
+    type Err = ParseUnitError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text, UNITS)
+    }
+}
+
+/// The position of `word` within `text`, which it is a slice of.
+fn offset(text: &str, word: &str) -> usize {
+    word.as_ptr() as usize - text.as_ptr() as usize
+}
+
+/// Find the unit and power which one factor of a composite unit is made of.
+fn find_factor(factor: &str, units: &[DynUnit]) -> Result<(DynUnit, i8), ParseUnitError> {
+    let (symbol, power) = split_power(factor)?;
+    let unit = DynUnit::find(symbol, units).ok_or(ParseUnitError::UnknownUnit)?;
+    Ok((unit, power))
+}
+
+/// Split a factor such as `m^2` or `s⁻¹` into its symbol and its power.
+fn split_power(factor: &str) -> Result<(&str, i8), ParseUnitError> {
+    if let Some((symbol, power)) = factor.split_once('^') {
+        let power = power.parse().map_err(|_| ParseUnitError::InvalidPower)?;
+        return Ok((symbol, power));
+    }
+    let start = factor
+        .char_indices()
+        .rev()
+        .take_while(|&(_, c)| c == '⁻' || superscript_digit(c).is_some())
+        .last()
+        .map_or(factor.len(), |(index, _)| index);
+    let (symbol, power) = factor.split_at(start);
+    if power.is_empty() {
+        return Ok((symbol, 1));
+    }
+    let (negative, digits) = match power.strip_prefix('⁻') {
+        Some(digits) => (true, digits),
+        None => (false, power),
+    };
+    if digits.is_empty() {
+        return Err(ParseUnitError::InvalidPower);
+    }
+    let mut value: i8 = 0;
+    for c in digits.chars() {
+        let digit = superscript_digit(c).ok_or(ParseUnitError::InvalidPower)?;
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit))
+            .ok_or(ParseUnitError::InvalidPower)?;
+    }
+    Ok((symbol, if negative { -value } else { value }))
+}
+
+fn superscript_digit(c: char) -> Option<i8> {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    DIGITS
+        .iter()
+        .position(|&digit| digit == c)
+        .map(|digit| digit as i8)
+}
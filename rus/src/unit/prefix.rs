@@ -0,0 +1,117 @@
+//! Families of units which differ only by a metric prefix, and choosing between them.
+//!
+//! Every unit created by [`create_unit_with_prefixes!`](crate::create_unit_with_prefixes) knows
+//! the other units generated alongside it, which lets a quantity be rescaled to the prefix that
+//! reads best.
+//! ```
+//! use rus::unit_defs as u;
+//! let time = 0.00012 * u::s;
+//! assert_eq!(format!("{:.0}", time.to_best_prefix()), "120 μs");
+//! let frequency = 3.2e9 * u::Hz;
+//! assert_eq!(format!("{:.1}", frequency.to_best_prefix()), "3.2 GHz");
+//! ```
+
+use super::{CompositeUnit, DynUnit, UnitInfo, UnitKind};
+use crate::quantity::SingleQuantity;
+use crate::scalar::Scalar;
+
+/// One unit in a family of units which differ only by a metric prefix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrefixedUnit {
+    /// The power of ten of the prefix. This is `0` for the unprefixed unit.
+    pub power: i8,
+    /// The description of the prefixed unit.
+    pub info: &'static UnitInfo,
+}
+
+/// Which prefixes [`SingleQuantity::to_best_prefix_with`] may choose from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrefixPolicy {
+    /// Any prefix in the family of the unit.
+    #[default]
+    All,
+    /// Only prefixes which are a power of one thousand, as in engineering notation.
+    ///
+    /// Of the SI prefixes, this excludes exactly hecto, deka, deci and centi.
+    Engineering,
+}
+
+impl PrefixPolicy {
+    fn allows(self, unit: &PrefixedUnit) -> bool {
+        match self {
+            Self::All => true,
+            Self::Engineering => unit.power % 3 == 0,
+        }
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar> SingleQuantity<Kind, S> {
+    /// Rescale this quantity to the metric prefix which keeps its value between 1 and 1000.
+    ///
+    /// The first unit of the quantity which was created with
+    /// [`create_unit_with_prefixes!`](crate::create_unit_with_prefixes) is the one which gets
+    /// rescaled. If there is no such unit, the quantity is returned unchanged.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let density = 1500. * u::kg / u::m / u::m / u::m;
+    /// assert_eq!(format!("{:.1}", density.to_best_prefix()), "1.5 Mg m^-3");
+    /// let rate = 2e4 * u::mole / u::s;
+    /// assert_eq!(format!("{:.0}", rate.to_best_prefix()), "20 kmol s^-1");
+    /// ```
+    pub fn to_best_prefix(&self) -> Self {
+        self.to_best_prefix_with(PrefixPolicy::All)
+    }
+
+    /// Rescale this quantity to the metric prefix allowed by `policy` which keeps its value
+    /// between 1 and 1000, as in [`SingleQuantity::to_best_prefix`].
+    /// ```
+    /// use rus::prefix::PrefixPolicy;
+    /// use rus::unit_defs as u;
+    /// let length = 0.05 * u::m;
+    /// assert_eq!(format!("{:.0}", length.to_best_prefix()), "5 cm");
+    /// let length = length.to_best_prefix_with(PrefixPolicy::Engineering);
+    /// assert_eq!(format!("{:.0}", length), "50 mm");
+    /// ```
+    pub fn to_best_prefix_with(&self, policy: PrefixPolicy) -> Self {
+        let units = &self.unit.component_units;
+        let Some(index) = units
+            .iter()
+            .position(|(unit, power)| !unit.info.prefixes.is_empty() && power != 0)
+        else {
+            return self.clone();
+        };
+        let value = self.scalar.to_f32();
+        if value == 0. || !value.is_finite() {
+            return self.clone();
+        }
+        let (unit, power) = (units.units()[index], units.powers()[index]);
+        let rescaled = |member: &PrefixedUnit| {
+            let mut scalar = value;
+            for _ in 0..power.unsigned_abs() {
+                if power > 0 {
+                    scalar *= unit.scale() / member.info.scale;
+                } else {
+                    scalar *= member.info.scale / unit.scale();
+                }
+            }
+            scalar.abs()
+        };
+        let candidates = unit
+            .info
+            .prefixes
+            .iter()
+            .filter(|member| policy.allows(member));
+        // The smallest value which is at least one, or failing that the largest value.
+        let best = candidates
+            .clone()
+            .filter(|member| rescaled(member) >= 1.)
+            .min_by(|a, b| rescaled(a).total_cmp(&rescaled(b)))
+            .or_else(|| candidates.max_by(|a, b| rescaled(a).total_cmp(&rescaled(b))));
+        let Some(best) = best else {
+            return self.clone();
+        };
+        let mut component_units = *units;
+        component_units.units_mut()[index] = DynUnit { info: best.info };
+        self.to(CompositeUnit::new(component_units))
+    }
+}
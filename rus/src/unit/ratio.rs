@@ -0,0 +1,174 @@
+/// An exact, positive scale factor, `numerator / denominator × 10^exponent`.
+///
+/// Units defined by [`create_unit!`](crate::create_unit) from decimal numbers and other units,
+/// such as `0.3048 * m` or `5 / 9 * K`, carry their scale factor exactly as well as in an `f32`.
+/// Integer quantities use it to convert between units without rounding the factor.
+/// ```
+/// use rus::imperial;
+/// use rus::Ratio;
+/// assert_eq!(imperial::inch.exact_scale(), Some(Ratio::new(254, 1, -4)));
+/// ```
+///
+/// A ratio is kept in lowest terms, with the factors of ten moved into the exponent, so that equal
+/// ratios compare equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ratio {
+    numerator: u128,
+    denominator: u128,
+    exponent: i32,
+}
+
+impl Ratio {
+    /// The ratio one.
+    pub const ONE: Self = Self::new(1, 1, 0);
+
+    /// Create the ratio `numerator / denominator × 10^exponent`.
+    ///
+    /// # Panics
+    /// Panics if the numerator or the denominator is zero.
+    pub const fn new(numerator: u128, denominator: u128, exponent: i32) -> Self {
+        assert!(
+            numerator != 0 && denominator != 0,
+            "a scale factor must be positive"
+        );
+        let divisor = gcd(numerator, denominator);
+        let (mut numerator, mut denominator) = (numerator / divisor, denominator / divisor);
+        let mut exponent = exponent;
+        // Turn factors of two and five in the denominator into powers of ten, while they fit, so
+        // that 5/2 and 25/10 are written the same way.
+        while denominator % 2 == 0 {
+            match numerator.checked_mul(5) {
+                Some(product) => numerator = product,
+                None => break,
+            }
+            denominator /= 2;
+            exponent -= 1;
+        }
+        while denominator % 5 == 0 {
+            match numerator.checked_mul(2) {
+                Some(product) => numerator = product,
+                None => break,
+            }
+            denominator /= 5;
+            exponent -= 1;
+        }
+        while numerator % 10 == 0 {
+            numerator /= 10;
+            exponent += 1;
+        }
+        while denominator % 10 == 0 {
+            denominator /= 10;
+            exponent -= 1;
+        }
+        Self {
+            numerator,
+            denominator,
+            exponent,
+        }
+    }
+
+    /// The reciprocal of this ratio.
+    pub const fn recip(self) -> Self {
+        Self::new(self.denominator, self.numerator, -self.exponent)
+    }
+
+    /// Multiply two ratios, or return `None` if the result does not fit.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let left = gcd(self.numerator, rhs.denominator);
+        let right = gcd(rhs.numerator, self.denominator);
+        let numerator = match (self.numerator / left).checked_mul(rhs.numerator / right) {
+            Some(numerator) => numerator,
+            None => return None,
+        };
+        let denominator = match (self.denominator / right).checked_mul(rhs.denominator / left) {
+            Some(denominator) => denominator,
+            None => return None,
+        };
+        match self.exponent.checked_add(rhs.exponent) {
+            Some(exponent) => Some(Self::new(numerator, denominator, exponent)),
+            None => None,
+        }
+    }
+
+    /// Divide two ratios, or return `None` if the result does not fit.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.recip())
+    }
+
+    /// Raise this ratio to an integer power, or return `None` if the result does not fit.
+    pub const fn checked_powi(self, power: i32) -> Option<Self> {
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut exponent = power.unsigned_abs();
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = match result.checked_mul(base) {
+                    Some(result) => result,
+                    None => return None,
+                };
+            }
+            exponent /= 2;
+            if exponent > 0 {
+                base = match base.checked_mul(base) {
+                    Some(base) => base,
+                    None => return None,
+                };
+            }
+        }
+        if power < 0 {
+            Some(result.recip())
+        } else {
+            Some(result)
+        }
+    }
+
+    /// This ratio as a fraction of integers, with the power of ten multiplied out, or `None` if
+    /// either does not fit in an `i128`.
+    pub(crate) fn fraction(self) -> Option<(i128, i128)> {
+        let power = 10_i128.checked_pow(self.exponent.unsigned_abs())?;
+        let numerator = i128::try_from(self.numerator).ok()?;
+        let denominator = i128::try_from(self.denominator).ok()?;
+        if self.exponent >= 0 {
+            Some((numerator.checked_mul(power)?, denominator))
+        } else {
+            Some((numerator, denominator.checked_mul(power)?))
+        }
+    }
+}
+
+/// The greatest common divisor of two positive integers.
+const fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+
+    #[test]
+    fn normalized() {
+        assert_eq!(Ratio::new(5, 2, 0), Ratio::new(25, 10, 0));
+        assert_eq!(Ratio::new(3048, 10_000, 0), Ratio::new(3048, 1, -4));
+        assert_eq!(Ratio::new(6, 4, 2), Ratio::new(15, 1, 1));
+        assert_eq!(Ratio::new(1, 3, 0).recip(), Ratio::new(3, 1, 0));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let foot = Ratio::new(3048, 1, -4);
+        let inch = foot.checked_div(Ratio::new(12, 1, 0)).unwrap();
+        assert_eq!(inch, Ratio::new(254, 1, -4));
+        assert_eq!(inch.fraction(), Some((254, 10_000)));
+        let fahrenheit = Ratio::new(5, 9, 0);
+        assert_eq!(fahrenheit.checked_powi(-2), Some(Ratio::new(81, 25, 0)));
+        assert_eq!(
+            Ratio::new(1, 1, 30).checked_powi(2).unwrap().fraction(),
+            None
+        );
+        let huge = Ratio::new(u128::MAX, 1, 0);
+        assert_eq!(huge.checked_mul(huge), None);
+    }
+}
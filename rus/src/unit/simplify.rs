@@ -0,0 +1,220 @@
+use super::kind::DynKind;
+use super::list::{UnitList, CAPACITY};
+use super::{CompositeUnit, DynUnit, UnitKind};
+use crate::math;
+use crate::quantity::SingleQuantity;
+use crate::scalar::Scalar;
+use crate::unit_defs::SI_BASE_UNITS;
+
+impl<Kind: UnitKind> CompositeUnit<Kind> {
+    /// Split this unit into a scale and the SI base units of the same kind.
+    ///
+    /// Multiplying a value in this unit by the scale gives the value in the base units, which are
+    /// written in the order m, kg, s, A, K, mol, cd.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let (scale, base) = (u::N * u::km / u::s).decompose();
+    /// assert_eq!(scale, 1e3);
+    /// assert_eq!(base.to_string(), "m^2 kg s^-3");
+    /// ```
+    pub fn decompose(&self) -> (f32, Self) {
+        (self.scale_factor(), Self::si())
+    }
+
+    /// The unit of this kind written in SI base units.
+    pub(crate) const fn si() -> Self {
+        let powers = Kind::DYN_KIND.powers();
+        let mut units = UnitList::new();
+        let mut index = 0;
+        while index < powers.len() {
+            if powers[index] != 0 {
+                units = units.with(SI_BASE_UNITS[index], powers[index]);
+            }
+            index += 1;
+        }
+        CompositeUnit::new(units)
+    }
+
+    /// Merge units of the same kind into one, such as `m km` into `m^2`.
+    ///
+    /// Each unit is merged into the first unit of its kind, and units whose powers cancel are
+    /// removed. Returns the scale which a value in this unit must be multiplied by to be in the
+    /// simplified unit, along with the simplified unit.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let (scale, area) = (u::m * u::km).simplify();
+    /// assert_eq!(scale, 1e3);
+    /// assert_eq!(area.to_string(), "m^2");
+    /// ```
+    pub fn simplify(&self) -> (f32, Self) {
+        let mut scale = 1.;
+        let mut units = UnitList::new();
+        for (unit, power) in self.component_units.iter() {
+            match units
+                .units()
+                .iter()
+                .position(|kept| kept.kind() == unit.kind())
+            {
+                Some(index) => {
+                    scale *= math::powi(unit.scale() / units.units()[index].scale(), power.into());
+                    units.powers_mut()[index] += power;
+                }
+                None => units.push((unit, power)),
+            }
+        }
+        units.retain(|_, power| power != 0);
+        (scale, CompositeUnit::new(units))
+    }
+
+    /// Replace groups of units with a single named unit of the same kind and scale, such as
+    /// `kg m^2 s^-2` with `J`.
+    ///
+    /// The named units to look for are given by `named`. A good default is
+    /// [`unit_defs::NAMED_DERIVED_UNITS`](crate::unit_defs::NAMED_DERIVED_UNITS). Only groups of
+    /// at least two units (counting powers) are replaced, so `s^-1` is not written as `Hz`.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let power = u::J / u::s;
+    /// assert_eq!(power.with_named_units(u::NAMED_DERIVED_UNITS).to_string(), "W");
+    /// let energy = u::kg * u::m * u::m / u::s / u::s / u::K;
+    /// assert_eq!(energy.with_named_units(u::NAMED_DERIVED_UNITS).to_string(), "J K^-1");
+    /// ```
+    pub fn with_named_units(&self, named: &[DynUnit]) -> Self {
+        let mut units = self.component_units;
+        // Every replacement lowers the total power of the units, so this terminates.
+        while let Some((named_unit, named_power, taken)) = find_named_factor(&units, named) {
+            let mut replaced = units;
+            let position = taken.iter().position(|power| *power != 0).unwrap_or(0);
+            for ((_, power), taken) in replaced.iter_mut().zip(taken) {
+                *power -= taken;
+            }
+            // Remove the used up units first, to make room for the named unit.
+            let position = replaced.powers()[..position]
+                .iter()
+                .filter(|power| **power != 0)
+                .count();
+            replaced.retain(|_, power| power != 0);
+            let existing = replaced.units().iter().position(|unit| *unit == named_unit);
+            if let Some(index) = existing {
+                replaced.powers_mut()[index] += named_power;
+            } else if replaced
+                .try_insert(position, (named_unit, named_power))
+                .is_none()
+            {
+                // No unit was used up, so there is no room for the named unit.
+                break;
+            }
+            replaced.retain(|_, power| power != 0);
+            units = replaced;
+        }
+        CompositeUnit::new(units)
+    }
+}
+
+impl<Kind: UnitKind, S: Scalar> SingleQuantity<Kind, S> {
+    /// Merge units of the same kind into one, as in [`CompositeUnit::simplify`], folding the
+    /// difference in scale into the value.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let area = 3. * u::m * u::km;
+    /// assert_eq!(area.simplify().to_string(), "3000 m^2");
+    /// let ratio = 2. * u::cm / u::m;
+    /// assert_eq!(ratio.simplify().to_string(), "0.02");
+    /// ```
+    pub fn simplify(&self) -> Self {
+        let (scale, unit) = self.unit.simplify();
+        Self::new(unit, self.scalar.clone().scale(scale))
+    }
+
+    /// Convert this quantity to the SI base units, as given by [`CompositeUnit::decompose`].
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let force = 2. * u::N;
+    /// assert_eq!(force.to_si().to_string(), "2 m kg s^-2");
+    /// let speed = 72. * u::km / u::ks;
+    /// assert_eq!(format!("{:.0}", speed.to_si()), "72 m s^-1");
+    /// ```
+    #[doc(alias = "decompose")]
+    pub fn to_si(&self) -> Self {
+        self.to(self.unit.decompose().1)
+    }
+
+    /// Convert this quantity to the unit given by [`CompositeUnit::with_named_units`].
+    /// ```
+    /// use rus::unit_defs as u;
+    /// let force = 3. * u::kg * u::m / u::s / u::s;
+    /// assert_eq!(force.with_named_units(u::NAMED_DERIVED_UNITS).to_string(), "3 N");
+    /// ```
+    pub fn with_named_units(&self, named: &[DynUnit]) -> Self {
+        self.to(self.unit.with_named_units(named))
+    }
+}
+
+/// Find the named unit which can replace the largest group of `units`.
+///
+/// Returns the named unit, the power it should have (`1` or `-1`), and the power taken from each
+/// of `units` to make it.
+///
+/// The search is greedy rather than exhaustive. For each named unit and power, the units are
+/// visited from the largest kind to the smallest, counting the powers of its base dimensions, and
+/// each takes whichever part of its power leaves the least of the named kind still to make. This
+/// takes `O(named.len() * units.len() * p)` steps for powers of at most `p`, but can miss a group
+/// which only an exhaustive search over every part of every power would find.
+fn find_named_factor(units: &UnitList, named: &[DynUnit]) -> Option<(DynUnit, i8, [i8; CAPACITY])> {
+    let size = |kind: DynKind| -> u32 {
+        kind.powers()
+            .into_iter()
+            .map(|power| u32::from(power.unsigned_abs()))
+            .sum()
+    };
+    let mut order: [usize; CAPACITY] = core::array::from_fn(|index| index);
+    let order = &mut order[..units.len()];
+    order.sort_unstable_by_key(|index| {
+        (
+            core::cmp::Reverse(size(units.units()[*index].kind())),
+            *index,
+        )
+    });
+
+    let mut best: Option<(DynUnit, i8, [i8; CAPACITY])> = None;
+    let mut best_coverage = 1;
+    let candidates = named
+        .iter()
+        .filter(|named_unit| !named_unit.kind().is_dimensionless())
+        .flat_map(|named_unit| [(*named_unit, 1), (*named_unit, -1)]);
+    for (named_unit, named_power) in candidates {
+        let mut remaining = named_unit.kind().powi(named_power);
+        let mut scale = 1.;
+        let mut taken = [0; CAPACITY];
+        for &index in order.iter() {
+            let (unit, power) = (units.units()[index], units.powers()[index]);
+            if unit == named_unit {
+                continue;
+            }
+            // The first part of the power which leaves the least still to make.
+            let part = (0..=power.unsigned_abs() as i8)
+                .map(|part| part * power.signum())
+                .min_by_key(|part| size(remaining * unit.kind().powi(-part)))
+                .unwrap_or(0);
+            remaining = remaining * unit.kind().powi(-part);
+            scale *= math::powi(unit.scale(), part.into());
+            taken[index] = part;
+        }
+        let coverage: u32 = taken
+            .iter()
+            .map(|part| u32::from(part.unsigned_abs()))
+            .sum();
+        if coverage > best_coverage
+            && remaining.is_dimensionless()
+            && approx_eq(math::powi(named_unit.scale(), named_power.into()), scale)
+        {
+            best = Some((named_unit, named_power, taken));
+            best_coverage = coverage;
+        }
+    }
+    best
+}
+
+fn approx_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() <= 1e-5 * a.abs().max(b.abs())
+}
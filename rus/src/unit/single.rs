@@ -1,40 +1,135 @@
-use super::{CompositeUnit, UnitKind};
+use super::list::UnitList;
+use super::{CompositeUnit, DynUnit, PrefixedUnit, Ratio, UnitInfo, UnitKind};
 use crate::quantity::SingleQuantity;
-use std::marker::PhantomData;
-use std::ops::{Div, Mul};
+use core::marker::PhantomData;
+use core::ops::{Div, Mul};
 use typenum::{Prod, Quot};
 
-pub(super) trait ToSingle {
-    type Single;
-}
-
 /// A individual unit, such as a second.
 ///
 /// This does not have to represent a base unit. For example, a Newton is also a single unit.
-#[derive(PartialEq, Clone, Copy)]
+///
+/// A unit is a reference to its [`UnitInfo`], so it is the size of a pointer.
+#[derive(Clone, Copy)]
 pub struct SingleUnit<Kind: UnitKind> {
+    info: &'static UnitInfo,
     _kind_marker: PhantomData<Kind>,
+}
+
+impl<Kind: UnitKind> SingleUnit<Kind> {
+    /// Describe a unit of this kind by its scale relative to the SI base unit equivalent, its
+    /// abbreviation, and its full name.
+    pub const fn info(scale: f32, abbreviation: &'static str, name: &'static str) -> UnitInfo {
+        UnitInfo {
+            kind: Kind::DYN_KIND,
+            scale,
+            exact_scale: None,
+            abbreviation,
+            ascii_abbreviation: abbreviation,
+            abbreviations: &[],
+            aliases: &[],
+            name,
+            plural: None,
+            prefixes: &[],
+        }
+    }
+
+    /// Create a new unit from its description, which must have been made for this kind.
+    ///
+    /// This is usually called through [`create_unit!`](crate::create_unit) rather than directly.
+    /// ```
+    /// use rus::kinds::LengthUnit;
+    /// const ft: LengthUnit = LengthUnit::new(&LengthUnit::info(0.3048, "ft", "foot"));
+    /// assert_eq!(ft.scale(), 0.3048);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `info` is the description of a unit of another kind.
+    pub const fn new(info: &'static UnitInfo) -> Self {
+        let (expected, actual) = (Kind::DYN_KIND.powers(), info.kind.powers());
+        let mut i = 0;
+        while i < expected.len() {
+            assert!(expected[i] == actual[i], "the unit is of another kind");
+            i += 1;
+        }
+        Self {
+            info,
+            _kind_marker: PhantomData,
+        }
+    }
+
     /// The scale from the SI base unit equivalent.
-    pub scale: f32,
+    pub const fn scale(&self) -> f32 {
+        self.info.scale
+    }
+
+    /// The exact scale from the SI base unit equivalent, if it is known. Units defined through π
+    /// or a measured value, and the units defined from them, have no exact scale.
+    /// ```
+    /// use rus::unit_defs as u;
+    /// use rus::Ratio;
+    /// assert_eq!(u::mV.exact_scale(), Some(Ratio::new(1, 1, -3)));
+    /// assert_eq!(u::keV.exact_scale(), Some(Ratio::new(1_602_176_634, 1, -25)));
+    /// assert_eq!(u::pc.exact_scale(), None);
+    /// assert_eq!(u::Da.exact_scale(), None);
+    /// ```
+    pub const fn exact_scale(&self) -> Option<Ratio> {
+        self.info.exact_scale
+    }
+
     /// An abbreviation for the unit.
-    pub abbreviation: &'static str,
+    pub const fn abbreviation(&self) -> &'static str {
+        self.info.abbreviation
+    }
+
+    /// The abbreviation written using only ASCII, such as `um` for `μm`.
+    pub const fn ascii_abbreviation(&self) -> &'static str {
+        self.info.ascii_abbreviation
+    }
+
+    /// The other spellings of the abbreviation which are accepted for this unit, such as `l` for
+    /// `L`.
+    pub const fn abbreviations(&self) -> &'static [&'static str] {
+        self.info.abbreviations
+    }
+
     /// The full name of the unit.
-    pub name: &'static str,
+    pub const fn name(&self) -> &'static str {
+        self.info.name
+    }
+
+    /// Forget the kind of this unit at compile time.
+    pub const fn to_dyn(self) -> DynUnit {
+        DynUnit { info: self.info }
+    }
+
+    /// The family of prefixed units which this unit belongs to. This is empty if the unit was not
+    /// created with [`create_unit_with_prefixes!`](crate::create_unit_with_prefixes).
+    pub const fn prefixes(&self) -> &'static [PrefixedUnit] {
+        self.info.prefixes
+    }
+}
+
+/// Compared in the same way as a [`DynUnit`].
+impl<Kind: UnitKind> PartialEq for SingleUnit<Kind> {
+    fn eq(&self, other: &Self) -> bool {
+        DynUnit { info: self.info } == DynUnit { info: other.info }
+    }
 }
 
-impl<Kind: UnitKind> std::fmt::Debug for SingleUnit<Kind> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<Kind: UnitKind> core::fmt::Debug for SingleUnit<Kind> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SingleUnit")
-            .field("scale", &self.scale)
-            .field("abbreviation", &self.abbreviation)
-            .field("name", &self.name)
+            .field("scale", &self.scale())
+            .field("abbreviation", &self.abbreviation())
+            .field("name", &self.name())
             .finish()
     }
 }
 
-impl<Kind: UnitKind> std::fmt::Display for SingleUnit<Kind> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.abbreviation)
+impl<Kind: UnitKind> core::fmt::Display for SingleUnit<Kind> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.abbreviation())
     }
 }
 
@@ -49,9 +144,9 @@ where
         let rhs = rhs.into();
         let dyn_self = self.into();
         if dyn_self == rhs {
-            CompositeUnit::new(vec![(dyn_self, 2)])
+            CompositeUnit::new([(dyn_self, 2)].into())
         } else {
-            CompositeUnit::new(vec![(dyn_self, 1), (rhs, 1)])
+            CompositeUnit::new([(dyn_self, 1), (rhs, 1)].into())
         }
     }
 }
@@ -79,9 +174,9 @@ where
         let rhs = rhs.into();
         let dyn_self = self.into();
         if dyn_self == rhs {
-            CompositeUnit::new(Vec::new())
+            CompositeUnit::new(UnitList::new())
         } else {
-            CompositeUnit::new(vec![(dyn_self, 1), (rhs, -1)])
+            CompositeUnit::new([(dyn_self, 1), (rhs, -1)].into())
         }
     }
 }
@@ -102,19 +197,62 @@ impl<Kind: UnitKind> Mul<SingleUnit<Kind>> for f32 {
     }
 }
 
+/// Aliases for [`SingleUnit`]s of each kind, for use as the declared type of a unit definition.
 pub mod kinds {
     use super::SingleUnit;
     use crate::unit::kind::*;
 
+    /// A unit of length.
     pub type LengthUnit = SingleUnit<LengthKind>;
+    /// A unit of mass.
     pub type MassUnit = SingleUnit<MassKind>;
+    /// A unit of time.
     pub type TimeUnit = SingleUnit<TimeKind>;
+    /// A unit of electric current.
     pub type CurrentUnit = SingleUnit<CurrentKind>;
+    /// A unit of temperature.
     pub type TemperatureUnit = SingleUnit<TemperatureKind>;
+    /// A unit of amount of substance.
     pub type AmountUnit = SingleUnit<AmountKind>;
+    /// A unit of luminous intensity.
     pub type LuminosityUnit = SingleUnit<LuminosityKind>;
 
+    /// A unit of plane angle.
+    pub type AngleUnit = SingleUnit<AngleKind>;
+
+    /// A unit of area.
+    pub type AreaUnit = SingleUnit<AreaKind>;
+    /// A unit of volume.
+    pub type VolumeUnit = SingleUnit<VolumeKind>;
+
+    /// A unit of velocity.
+    pub type VelocityUnit = SingleUnit<VelocityKind>;
+    /// A unit of frequency.
+    pub type FrequencyUnit = SingleUnit<FrequencyKind>;
+    /// A unit of force.
     pub type ForceUnit = SingleUnit<ForceKind>;
+    /// A unit of energy.
+    pub type EnergyUnit = SingleUnit<EnergyKind>;
+    /// A unit of power.
+    pub type PowerUnit = SingleUnit<PowerKind>;
+    /// A unit of electric potential difference.
+    pub type VoltageUnit = SingleUnit<VoltageKind>;
+    /// A unit of electrical resistance.
+    pub type ResistanceUnit = SingleUnit<ResistanceKind>;
+    /// A unit of pressure.
+    pub type PressureUnit = SingleUnit<PressureKind>;
+    /// A unit of electric charge.
+    pub type ChargeUnit = SingleUnit<ChargeKind>;
+    /// A unit of magnetic flux density.
+    pub type MagneticFluxDensityUnit = SingleUnit<MagneticFluxDensityKind>;
+    /// A unit of dynamic viscosity.
+    pub type DynamicViscosityUnit = SingleUnit<DynamicViscosityKind>;
+    /// A unit of kinematic viscosity.
+    pub type KinematicViscosityUnit = SingleUnit<KinematicViscosityKind>;
+    /// A unit of spectral flux density.
+    pub type SpectralFluxDensityUnit = SingleUnit<SpectralFluxDensityKind>;
+    /// A unit of photon radiance.
+    pub type PhotonRadianceUnit = SingleUnit<PhotonRadianceKind>;
 }
 
 /// Module containing the definitions of all units.
@@ -123,6 +261,7 @@ pub mod kinds {
 /// it is recommended to `use units::unit_defs as u` and then use `u::cm` in order to access all the units.
 pub mod unit_defs {
     use super::kinds::*;
+    use crate::unit::DynUnit;
     use rus_macros::{create_unit, create_unit_with_prefixes};
 
     create_unit_with_prefixes!(
@@ -137,39 +276,436 @@ pub mod unit_defs {
         /// Due to how prefixed units are generated, currently the specific documentation cannot go
         /// on the kilogram.
         g: MassUnit = 1e-3,
-        "gram"
+        "gram",
+        prefixes = engineering
     );
     create_unit_with_prefixes!(
         /// A second. The SI unit of time.
         s: TimeUnit = 1.,
-        "second"
+        "second",
+        prefixes = engineering
     );
     create_unit_with_prefixes!(
         /// An Ampere. The SI unit of current.
         A: CurrentUnit = 1.,
-        "ampere"
+        "ampere",
+        prefixes = engineering
     );
     create_unit_with_prefixes!(
         /// A Kelvin. The SI unit of temperature.
         K: TemperatureUnit = 1.,
-        "kelvin"
+        "kelvin",
+        prefixes = engineering
     );
     create_unit_with_prefixes!(
         /// A mole. The SI unit of amount.
         mole: AmountUnit = 1.,
-        "mole"
+        "mole" "mol",
+        prefixes = engineering,
+        aliases = [mol]
     );
     create_unit_with_prefixes!(
         /// A candela. The SI unit of luminous intensity.
         cd: LuminosityUnit = 1.,
-        "candela"
+        "candela",
+        prefixes = engineering,
+        aliases = [candela]
+    );
+
+    create_unit!(
+        /// An ångström. A unit of length used for atomic scales, one ten-billionth of a meter.
+        angstrom: LengthUnit = 1e-10 * m,
+        "ångström" "Å",
+        ascii = "Ang",
+        abbreviations = ["\u{212b}"]
+    );
+
+    create_unit!(
+        /// A radian. The SI unit of plane angle.
+        rad: AngleUnit = 1.,
+        "radian"
+    );
+    create_unit!(
+        /// A degree of arc. One 360th of a full turn.
+        deg: AngleUnit = 0.017_453_292 * rad,
+        "degree" "°",
+        ascii = "deg",
+        exact = false
     );
 
+    create_unit_with_prefixes!(
+        /// A hertz. The derived unit of frequency.
+        Hz: FrequencyUnit = 1 / s,
+        "hertz",
+        prefixes = engineering
+    );
     create_unit!(
         /// A Newton. The derived unit of force.
-        N: ForceUnit = kg * m / (s * s),
-        "Newton"
+        N: ForceUnit = kg * m / s^2,
+        "newton"
+    );
+    create_unit_with_prefixes!(
+        /// A joule. The derived unit of energy.
+        J: EnergyUnit = N * m,
+        "joule",
+        prefixes = engineering
+    );
+    create_unit_with_prefixes!(
+        /// A watt. The derived unit of power.
+        W: PowerUnit = J / s,
+        "watt",
+        prefixes = engineering
+    );
+    create_unit_with_prefixes!(
+        /// A volt. The derived unit of electric potential difference.
+        V: VoltageUnit = W / A,
+        "volt",
+        prefixes = engineering
+    );
+    create_unit_with_prefixes!(
+        /// An ohm. The derived unit of electrical resistance.
+        ohm: ResistanceUnit = kg * m^2 / (s^3 * A^2),
+        "ohm" "Ω",
+        ascii = "ohm",
+        abbreviations = ["\u{2126}"],
+        prefixes = engineering
+    );
+    create_unit_with_prefixes!(
+        /// A pascal. The derived unit of pressure.
+        Pa: PressureUnit = N / m^2,
+        "pascal",
+        prefixes = [m, h, k, M, G]
+    );
+
+    create_unit!(
+        /// A minute. Sixty seconds.
+        min: TimeUnit = 60 * s,
+        "minute" "min",
+        aliases = [minute]
+    );
+    create_unit!(
+        /// An hour. Sixty minutes.
+        h: TimeUnit = 60 * min,
+        "hour" "h",
+        abbreviations = ["hr"],
+        aliases = [hr, hour]
+    );
+    create_unit!(
+        /// A day. Twenty-four hours.
+        d: TimeUnit = 24 * h,
+        "day" "d",
+        aliases = [day]
+    );
+    create_unit!(
+        /// A week. Seven days.
+        wk: TimeUnit = 7 * d,
+        "week" "wk",
+        aliases = [week]
+    );
+
+    create_unit!(
+        /// A hectare. The area of a square one hundred meters on a side.
+        ha: AreaUnit = 1e4 * m^2,
+        "hectare" "ha",
+        aliases = [hectare]
+    );
+    create_unit_with_prefixes!(
+        /// A liter. One cubic decimeter.
+        L: VolumeUnit = dm^3,
+        "liter" "L",
+        abbreviations = ["l"],
+        prefixes = [u, m, c, d, h, k],
+        aliases = [l]
+    );
+    create_unit_with_prefixes!(
+        /// A tonne. One thousand kilograms.
+        t: MassUnit = 1000 * kg,
+        "tonne" "t",
+        prefixes = [k, M, G],
+        aliases = [tonne]
+    );
+
+    create_unit_with_prefixes!(
+        /// A bar. One hundred thousand pascals.
+        bar: PressureUnit = 1e5 * Pa,
+        "bar",
+        prefixes = [m]
+    );
+    create_unit!(
+        /// A standard atmosphere.
+        atm: PressureUnit = 101_325 * Pa,
+        "atmosphere" "atm"
+    );
+    create_unit!(
+        /// A torr. One 760th of a standard atmosphere.
+        torr: PressureUnit = atm / 760,
+        "torr" "Torr",
+        plural = "torr"
+    );
+    create_unit!(
+        /// A conventional millimeter of mercury, the pressure of a millimeter of mercury with a
+        /// density of 13 595.1 kilograms per cubic meter under standard gravity.
+        mmHg: PressureUnit = 13_595.1 * 9.806_65 * 1e-3 * Pa,
+        "millimeter of mercury" "mmHg",
+        plural = "millimeters of mercury"
+    );
+
+    create_unit_with_prefixes!(
+        /// A thermochemical calorie.
+        cal: EnergyUnit = 4.184 * J,
+        "calorie" "cal",
+        prefixes = [k]
     );
+    create_unit!(
+        /// A kilowatt hour.
+        kWh: EnergyUnit = kW * h,
+        "kilowatt hour" "kWh"
+    );
+
+    create_unit!(
+        /// A kilometer per hour, as a single unit.
+        kmh: VelocityUnit = km / h,
+        "kilometer per hour" "km/h",
+        plural = "kilometers per hour"
+    );
+    create_unit!(
+        /// A nautical mile.
+        nmi: LengthUnit = 1852 * m,
+        "nautical mile" "nmi"
+    );
+    create_unit!(
+        /// A knot. One nautical mile per hour.
+        kn: VelocityUnit = nmi / h,
+        "knot" "kn",
+        aliases = [knot]
+    );
+    create_unit!(
+        /// A revolution per minute. Revolutions are counted like cycles, so this is one sixtieth of
+        /// a hertz rather than 2π / 60 radians per second.
+        rpm: FrequencyUnit = 1 / min,
+        "revolution per minute" "rpm",
+        plural = "revolutions per minute"
+    );
+
+    pub use crate::cgs::erg;
+
+    create_unit!(
+        /// An arcminute. One sixtieth of a degree.
+        arcmin: AngleUnit = deg / 60,
+        "arcminute" "′",
+        ascii = "arcmin"
+    );
+    create_unit!(
+        /// An arcsecond. One sixtieth of an arcminute.
+        arcsec: AngleUnit = arcmin / 60,
+        "arcsecond" "″",
+        ascii = "arcsec"
+    );
+    create_unit!(
+        /// A milliarcsecond.
+        mas: AngleUnit = arcsec / 1000,
+        "milliarcsecond" "mas"
+    );
+    create_unit!(
+        /// A microarcsecond.
+        uas: AngleUnit = mas / 1000,
+        "microarcsecond" "μas",
+        ascii = "uas"
+    );
+
+    create_unit_with_prefixes!(
+        /// A Julian year of 365.25 days, as used to define the light-year.
+        yr: TimeUnit = 365.25 * d,
+        "year" "yr",
+        prefixes = [k, M, G]
+    );
+    create_unit!(
+        /// An astronomical unit. Roughly the mean distance between the Earth and the Sun.
+        AU: LengthUnit = 149_597_870_700 * m,
+        "astronomical unit" "au",
+        abbreviations = ["AU", "ua"],
+        aliases = [au]
+    );
+    create_unit!(
+        /// A light-year. The distance light travels in a Julian year.
+        ly: LengthUnit = 299_792_458 * m / s * yr,
+        "light-year" "ly"
+    );
+    create_unit_with_prefixes!(
+        /// A parsec. The distance at which one astronomical unit subtends one arcsecond.
+        pc: LengthUnit = AU * rad / arcsec,
+        "parsec" "pc",
+        prefixes = [k, M, G]
+    );
+
+    create_unit!(
+        /// The nominal mass of the Sun.
+        M_sun: MassUnit = 1.988_47e30 * kg,
+        "solar mass" "M☉",
+        plural = "solar masses",
+        ascii = "Msun",
+        exact = false
+    );
+    create_unit!(
+        /// The nominal radius of the Sun, as defined by the IAU.
+        R_sun: LengthUnit = 6.957e8 * m,
+        "solar radius" "R☉",
+        plural = "solar radii",
+        ascii = "Rsun"
+    );
+    create_unit!(
+        /// The nominal luminosity of the Sun, as defined by the IAU.
+        L_sun: PowerUnit = 3.828e26 * W,
+        "solar luminosity" "L☉",
+        plural = "solar luminosities",
+        ascii = "Lsun"
+    );
+    create_unit!(
+        /// The nominal mass of the Earth.
+        M_earth: MassUnit = 5.972_17e24 * kg,
+        "Earth mass" "M⊕",
+        plural = "Earth masses",
+        ascii = "Mearth",
+        exact = false
+    );
+    create_unit!(
+        /// The nominal mass of Jupiter.
+        M_jup: MassUnit = 1.898_13e27 * kg,
+        "Jupiter mass" "M♃",
+        plural = "Jupiter masses",
+        ascii = "Mjup",
+        exact = false
+    );
+
+    create_unit_with_prefixes!(
+        /// A jansky. A unit of spectral flux density used in radio astronomy.
+        Jy: SpectralFluxDensityUnit = 1e-26 * W / (m^2 * Hz),
+        "jansky",
+        prefixes = [m, u]
+    );
+    create_unit!(
+        /// A rayleigh. A unit of photon radiance used for airglow and aurorae, equal to 10^10 / 4π
+        /// photons per second per square meter per steradian.
+        R: PhotonRadianceUnit = 7.957_747e8 / (m^2 * s),
+        "rayleigh",
+        aliases = [rayleigh],
+        exact = false
+    );
+
+    create_unit_with_prefixes!(
+        /// An electronvolt. The energy gained by an electron accelerated through one volt.
+        eV: EnergyUnit = 1.602_176_634e-19 * J,
+        "electronvolt" "eV",
+        prefixes = [m, k, M, G, T, P]
+    );
+    create_unit_with_prefixes!(
+        /// A barn. A unit of cross-section used in nuclear and particle physics.
+        b: AreaUnit = 1e-28 * m^2,
+        "barn" "b",
+        prefixes = [m, u, n, p, f, a]
+    );
+    /// An alias of [`fm`]. A fermi is the same unit as a femtometer, so it is written as `fm`.
+    #[allow(non_upper_case_globals)]
+    pub const fermi: LengthUnit = fm;
+    create_unit_with_prefixes!(
+        /// A dalton, or unified atomic mass unit. One twelfth of the mass of a carbon-12 atom.
+        Da: MassUnit = 1.660_539_1e-27 * kg,
+        "dalton" "Da",
+        prefixes = [k, M],
+        aliases = [amu],
+        exact = false
+    );
+    create_unit!(
+        /// A hartree. The atomic unit of energy.
+        Eh: EnergyUnit = 4.359_744_7e-18 * J,
+        "hartree" "Eₕ",
+        ascii = "Eh",
+        aliases = [hartree],
+        exact = false
+    );
+
+    /// The SI base units, in the same order as [`DynKind::powers`](crate::kind::DynKind::powers).
+    pub const SI_BASE_UNITS: [DynUnit; 7] = [
+        m.to_dyn(),
+        kg.to_dyn(),
+        s.to_dyn(),
+        A.to_dyn(),
+        K.to_dyn(),
+        mole.to_dyn(),
+        cd.to_dyn(),
+    ];
+
+    /// The named derived units, for use with
+    /// [`CompositeUnit::with_named_units`](crate::CompositeUnit::with_named_units).
+    pub const NAMED_DERIVED_UNITS: &[DynUnit] = &[
+        Hz.to_dyn(),
+        N.to_dyn(),
+        J.to_dyn(),
+        W.to_dyn(),
+        V.to_dyn(),
+        ohm.to_dyn(),
+        Pa.to_dyn(),
+    ];
+
+    /// Every unit defined here, for looking units up by name with [`DynUnit::find`] and
+    /// [`CompositeUnit::parse`](crate::CompositeUnit::parse). Their prefixed units are found through
+    /// their [`prefixes`](crate::UnitInfo::prefixes).
+    pub const UNITS: &[DynUnit] = &[
+        m.to_dyn(),
+        g.to_dyn(),
+        s.to_dyn(),
+        A.to_dyn(),
+        K.to_dyn(),
+        mole.to_dyn(),
+        cd.to_dyn(),
+        angstrom.to_dyn(),
+        rad.to_dyn(),
+        deg.to_dyn(),
+        Hz.to_dyn(),
+        N.to_dyn(),
+        J.to_dyn(),
+        W.to_dyn(),
+        V.to_dyn(),
+        ohm.to_dyn(),
+        Pa.to_dyn(),
+        min.to_dyn(),
+        h.to_dyn(),
+        d.to_dyn(),
+        wk.to_dyn(),
+        ha.to_dyn(),
+        L.to_dyn(),
+        t.to_dyn(),
+        bar.to_dyn(),
+        atm.to_dyn(),
+        torr.to_dyn(),
+        mmHg.to_dyn(),
+        cal.to_dyn(),
+        kWh.to_dyn(),
+        kmh.to_dyn(),
+        nmi.to_dyn(),
+        kn.to_dyn(),
+        rpm.to_dyn(),
+        arcmin.to_dyn(),
+        arcsec.to_dyn(),
+        mas.to_dyn(),
+        uas.to_dyn(),
+        yr.to_dyn(),
+        AU.to_dyn(),
+        ly.to_dyn(),
+        pc.to_dyn(),
+        M_sun.to_dyn(),
+        R_sun.to_dyn(),
+        L_sun.to_dyn(),
+        M_earth.to_dyn(),
+        M_jup.to_dyn(),
+        Jy.to_dyn(),
+        R.to_dyn(),
+        eV.to_dyn(),
+        b.to_dyn(),
+        Da.to_dyn(),
+        Eh.to_dyn(),
+        erg.to_dyn(),
+    ];
 }
 
 #[cfg(test)]
@@ -185,4 +721,75 @@ mod test {
     fn use_km() {
         println!("{}", km);
     }
+
+    #[test]
+    fn use_2022_prefixes() {
+        assert_eq!(Qm.scale(), 1e30);
+        assert_eq!(Qm.abbreviation(), "Qm");
+        assert_eq!(Rg.name(), "ronnagram");
+        assert_eq!(qs.scale(), 1e-30);
+        assert_eq!(rs.name(), "rontosecond");
+    }
+
+    #[test]
+    fn use_aliases() {
+        assert_eq!(mol, mole);
+        assert_eq!(kmol, kmole);
+        assert_eq!(kmol.abbreviation(), "kmol");
+        assert_eq!(candela, cd);
+        assert_eq!(l, L);
+        assert_eq!(ml, mL);
+    }
+
+    #[test]
+    fn equality_ignores_prefix_family() {
+        use super::kinds::LengthUnit;
+        const BARE_KM: LengthUnit = LengthUnit::new(&LengthUnit::info(1000., "km", "kilometer"));
+        assert_eq!(km, BARE_KM);
+        assert_eq!(km.to_dyn(), BARE_KM.to_dyn());
+        assert_ne!(km.to_dyn(), m.to_dyn());
+    }
+
+    #[test]
+    fn unicode_abbreviations() {
+        assert_eq!(um.abbreviation(), "μm");
+        assert_eq!(kohm.abbreviation(), "kΩ");
+        assert_eq!(angstrom.abbreviation(), "Å");
+        assert_eq!(deg.abbreviation(), "°");
+    }
+
+    #[test]
+    fn ascii_abbreviations() {
+        assert_eq!(um.ascii_abbreviation(), "um");
+        assert_eq!(kohm.ascii_abbreviation(), "kohm");
+        assert_eq!(angstrom.ascii_abbreviation(), "Ang");
+        assert_eq!(M_sun.ascii_abbreviation(), "Msun");
+        assert_eq!(km.ascii_abbreviation(), "km");
+    }
+
+    #[test]
+    fn alternative_abbreviations() {
+        assert_eq!(um.abbreviations(), ["um", "\u{b5}m"]);
+        assert_eq!(mL.abbreviations(), ["ml"]);
+        assert_eq!(kohm.abbreviations(), ["kohm", "k\u{2126}"]);
+        assert_eq!(AU.abbreviations(), ["AU", "ua"]);
+        assert!(h.to_dyn().info().has_abbreviation("hr"));
+        assert_eq!(h.to_dyn().info().name(), "hour");
+        assert!(m.abbreviations().is_empty());
+    }
+
+    #[test]
+    fn select_prefixes() {
+        use super::kinds::LengthUnit;
+        use rus_macros::create_unit_with_prefixes;
+        create_unit_with_prefixes!(
+            /// A test unit.
+            x: LengthUnit = 2.,
+            "test unit",
+            prefixes = [k, u]
+        );
+        assert_eq!(x.scale(), 2.);
+        assert_eq!(kx.name(), "kilotest unit");
+        assert_eq!(ux.abbreviation(), "μx");
+    }
 }
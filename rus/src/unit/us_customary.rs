@@ -0,0 +1,50 @@
+//! Units of the United States customary system.
+//!
+//! The units shared with the [`imperial`](crate::imperial) system have been identical since the
+//! international yard and pound agreement of 1959, so they are re-exported from there. Only the
+//! units of liquid volume differ.
+//! ```
+//! use rus::unit_defs as u;
+//! use rus::{imperial, us_customary as us};
+//! let fuel = 1. * us::gal;
+//! assert_eq!(format!("{:.3}", fuel.to(u::dm * u::dm * u::dm)), "3.785 dm^3");
+//! assert_eq!(format!("{:.3}", fuel.to(imperial::gal)), "0.833 imp gal");
+//! ```
+
+use crate::kinds::*;
+use crate::DynUnit;
+use rus_macros::create_unit;
+
+pub use super::imperial::{
+    delta_degF, foot, ft, hp, inch, lb, lbf, mi, mile, ounce, oz, pound, psi, yard, yd, BTU,
+};
+
+create_unit!(
+    /// A US liquid gallon, 231 cubic inches.
+    gal: VolumeUnit = 231 * inch^3,
+    "US gallon" "US gal",
+    aliases = [gallon]
+);
+create_unit!(
+    /// A US fluid ounce, one 128th of a US gallon.
+    fl_oz: VolumeUnit = gal / 128,
+    "US fluid ounce" "US fl oz"
+);
+
+/// The US customary units, including those shared with the imperial system, for looking units up
+/// by name with [`DynUnit::find`] and [`CompositeUnit::parse`](crate::CompositeUnit::parse).
+pub const UNITS: &[DynUnit] = &[
+    inch.to_dyn(),
+    ft.to_dyn(),
+    yd.to_dyn(),
+    mi.to_dyn(),
+    lb.to_dyn(),
+    oz.to_dyn(),
+    lbf.to_dyn(),
+    gal.to_dyn(),
+    fl_oz.to_dyn(),
+    psi.to_dyn(),
+    BTU.to_dyn(),
+    hp.to_dyn(),
+    delta_degF.to_dyn(),
+];
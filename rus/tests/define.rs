@@ -0,0 +1,102 @@
+use rus::kinds::{ForceUnit, LengthUnit, TimeUnit};
+use rus::static_unit::{Per, StaticQuantity, StaticUnit};
+use rus::unit_defs as u;
+use rus::{create_static_unit, create_unit, create_unit_with_prefixes, Ratio};
+
+create_unit!(
+    /// An international foot.
+    ft: LengthUnit = 0.3048 * u::m,
+    "foot"
+);
+
+create_unit!(
+    /// An hour.
+    hr: TimeUnit = 3600 * u::s,
+    "hour"
+);
+
+create_unit!(
+    /// A second, defined backwards from an hour.
+    sec: TimeUnit = 1 / 3600 * hr,
+    "second"
+);
+
+create_unit_with_prefixes!(
+    /// A Newton, defined using a power.
+    newt: ForceUnit = u::kg * u::m * u::s^-2,
+    "newt",
+    prefixes = [k]
+);
+
+create_unit!(
+    /// A cubit, whose length is only roughly known.
+    cubit: LengthUnit = 0.45 * u::m,
+    "cubit",
+    exact = false
+);
+
+create_unit!(
+    /// A Newton, defined using `powi`.
+    newt2: ForceUnit = u::kg * (u::m / u::s.powi(2)),
+    "newt"
+);
+
+create_static_unit!(
+    /// A kilometer, known at compile time.
+    Km: LengthUnit = u::km
+);
+
+create_static_unit!(
+    /// An international foot, known at compile time.
+    pub(crate) Ft: LengthUnit = ft
+);
+
+create_static_unit!(Minute: TimeUnit = u::min);
+
+#[test]
+fn numeric_constants() {
+    assert_eq!(ft.scale(), 0.3048);
+    assert_eq!(hr.scale(), 3600.);
+    assert_eq!(sec.scale(), 1.);
+}
+
+#[test]
+fn exact_scales() {
+    assert_eq!(ft.exact_scale(), Some(Ratio::new(3048, 1, -4)));
+    assert_eq!(sec.exact_scale(), Some(Ratio::ONE));
+    assert_eq!(cubit.scale(), 0.45);
+    assert_eq!(cubit.exact_scale(), None);
+}
+
+#[test]
+fn powers() {
+    assert_eq!(newt.scale(), u::N.scale());
+    assert_eq!(knewt.scale(), 1e3 * u::N.scale());
+    assert_eq!(newt2.scale(), u::N.scale());
+}
+
+#[test]
+fn static_units() {
+    assert_eq!(Km::SCALE, 1e3);
+    assert_eq!(Ft::SCALE, 0.3048);
+    let length = 1. * Km;
+    assert_eq!(format!("{:.1}", length.to::<Ft>()), "3280.8 ft");
+    let speed: StaticQuantity<Per<Km, Minute>> = length / (4. * Minute);
+    assert_eq!(speed.to_string(), "0.25 km min^-1");
+    assert_eq!(speed, StaticQuantity::new(0.25));
+    assert!(1. * Km > 0.5 * Km);
+}
+
+#[test]
+fn integer_static_units() {
+    use rus::scalar::Rounding;
+    assert_eq!(Km::EXACT_SCALE, Some(Ratio::new(1, 1, 3)));
+    assert_eq!(<Per<Km, Minute>>::EXACT_SCALE, Some(Ratio::new(50, 3, 0)));
+    let length = 5_i64 * Km;
+    assert_eq!(length.to::<Ft>().scalar(), &16404);
+    assert_eq!(
+        length.checked_to::<Ft>(Rounding::Up).unwrap().scalar(),
+        &16405
+    );
+    assert!((1_i8 * Km).checked_to::<Ft>(Rounding::Nearest).is_none());
+}
@@ -33,7 +33,7 @@ fn using_to() {
 fn equality_different_units() {
     let distance = 12. * u::cm;
     let time = 2. * u::s;
-    let velocity1 = distance.clone() / time.clone();
+    let velocity1 = distance / time;
     let velocity2 = distance.to(u::m) / time;
     assert_eq!(velocity1, velocity2);
 }
@@ -42,7 +42,462 @@ fn equality_different_units() {
 fn equality_same_units() {
     let distance = 12. * u::cm;
     let time = 2. * u::s;
-    let velocity1 = distance.clone() / time.clone();
+    let velocity1 = distance / time;
     let velocity2 = distance.to(u::m) / time;
     assert!(!velocity1.unit_eq(velocity2));
 }
+
+#[test]
+fn display_ascii() {
+    let acceleration = 9.8 * u::m / u::s / u::s;
+    assert_eq!(format!("{acceleration}"), "9.8 m s^-2");
+}
+
+#[test]
+fn display_superscript() {
+    let acceleration = 9.8 * u::m / u::s / u::s;
+    assert_eq!(format!("{acceleration:#}"), "9.8 m·s⁻²");
+    let resistance = 4.7 * u::kohm;
+    assert_eq!(format!("{resistance:#}"), "4.7 kΩ");
+}
+
+#[test]
+fn display_formats() {
+    use rus::format::{Exponents, Separator, UnitFormat, UnitStyle};
+    let torque = 3. * u::N * u::km;
+    let latex = UnitFormat::new().style(UnitStyle::Latex);
+    assert_eq!(
+        torque.format(latex).to_string(),
+        r"\SI{3}{\newton\kilo\metre}"
+    );
+    let energy = 2. * u::kJ;
+    assert_eq!(energy.format(latex).to_string(), r"\SI{2}{\kilo\joule}");
+    let field = 5. * u::V / u::m;
+    assert_eq!(field.format(latex).to_string(), r"\SI{5}{\volt\per\metre}");
+    let power = 1. * u::MW / u::Hz;
+    assert_eq!(
+        power.format(latex).to_string(),
+        r"\SI{1}{\mega\watt\per\hertz}"
+    );
+    let pretty = UnitFormat::new()
+        .style(UnitStyle::Fraction)
+        .separator(Separator::MiddleDot)
+        .exponents(Exponents::Superscript);
+    let jerk = 1. * u::m / u::s / u::s / u::s;
+    assert_eq!(jerk.format(pretty).to_string(), "1 m / s³");
+    let long = UnitFormat::new().style(UnitStyle::LongName);
+    assert_eq!(torque.format(long).to_string(), "3 newton kilometers");
+    let feet = 6. * rus::imperial::ft;
+    assert_eq!(feet.format(long).to_string(), "6 feet");
+    let inches = 2. * rus::imperial::inch;
+    assert_eq!(inches.format(long).to_string(), "2 inches");
+    let speed = 90. * u::kmh;
+    assert_eq!(speed.format(long).to_string(), "90 kilometers per hour");
+    let pressure = 760. * u::mmHg;
+    assert_eq!(
+        pressure.format(long).to_string(),
+        "760 millimeters of mercury"
+    );
+    let pressure = 1. * u::mmHg;
+    assert_eq!(pressure.format(long).to_string(), "1 millimeter of mercury");
+    let frequency = 3. * u::Hz;
+    assert_eq!(frequency.format(long).to_string(), "3 hertz");
+}
+
+#[test]
+fn best_prefix() {
+    let length = -4500. * u::m;
+    assert_eq!(format!("{:.1}", length.to_best_prefix()), "-4.5 km");
+    let force = 4500. * u::N;
+    assert!(force.to_best_prefix().unit_eq(force));
+    let tiny = 1e-35 * u::m;
+    assert_eq!(format!("{:.0}", tiny.to_best_prefix()), "0 qm");
+}
+
+#[test]
+fn named_units() {
+    let power = 2. * u::kg * u::m * u::m / u::s / u::s / u::s;
+    let named = power.with_named_units(u::NAMED_DERIVED_UNITS);
+    assert_eq!(named.to_string(), "2 W");
+    let resistance = 1. * u::kg * u::m * u::m / u::s / u::s / u::s / u::A / u::A;
+    let named = resistance.with_named_units(u::NAMED_DERIVED_UNITS);
+    assert_eq!(named.to_string(), "1 Ω");
+    let speed = 5. * u::m / u::s;
+    assert_eq!(
+        speed.with_named_units(u::NAMED_DERIVED_UNITS).to_string(),
+        "5 m s^-1"
+    );
+    let energy = 3. * u::W * u::s;
+    let named = energy.with_named_units(u::NAMED_DERIVED_UNITS);
+    assert_eq!(named.to_string(), "3 J");
+    let voltage = 12. * u::W / u::A;
+    let named = voltage.with_named_units(u::NAMED_DERIVED_UNITS);
+    assert_eq!(named.to_string(), "12 V");
+    // Large powers are found quickly, and repeated named units are merged.
+    let power = (1. * u::kg * u::m * u::m / u::s / u::s / u::s).powi(rus::typenum::P4::new());
+    let named = power.with_named_units(u::NAMED_DERIVED_UNITS);
+    assert_eq!(named.to_string(), "1 W^4");
+}
+
+#[test]
+fn to_si() {
+    let rate = 3. * u::g / u::ms;
+    assert_eq!(format!("{:.1}", rate.to_si()), "3.0 kg s^-1");
+    let energy = 2. * u::kJ;
+    assert_eq!(format!("{:.0}", energy.to_si()), "2000 m^2 kg s^-2");
+}
+
+#[test]
+fn simplify() {
+    let volume = 2. * u::m * u::cm * u::km;
+    assert_eq!(format!("{:.0}", volume.simplify()), "20 m^3");
+    let speed = (1. * u::m / u::s) * (1. * u::s * u::s);
+    assert_eq!(speed.to_string(), "1 m s");
+    let ratio = (4. * u::m / u::s) / (2. * u::m / u::s);
+    assert_eq!(ratio.to_string(), "2");
+}
+
+#[test]
+fn unit_systems() {
+    use rus::{cgs, imperial, us_customary as us};
+    let pressure = 1. * us::psi;
+    assert_eq!(
+        format!("{:.0}", pressure.to(u::N / u::m / u::m)),
+        "6895 N m^-2"
+    );
+    let engine = 1. * imperial::hp;
+    assert_eq!(format!("{:.1}", engine.to(u::W)), "745.7 W");
+    let heat = 1. * imperial::BTU;
+    assert_eq!(format!("{:.1}", heat.to(u::J)), "1055.1 J");
+    assert_eq!(format!("{:.3e}", heat.to(cgs::erg)), "1.055e10 erg");
+    let warming = 9. * imperial::delta_degF;
+    assert_eq!(format!("{:.0}", warming.to(u::K)), "5 K");
+    let charge = 1. * cgs::statC;
+    assert_eq!(format!("{:.3e}", charge.to(u::A * u::s)), "3.336e-10 A s");
+    let distance = 1. * us::mile;
+    assert_eq!(format!("{:.3}", distance.to(u::km)), "1.609 km");
+    let fuel = 1. * us::gal;
+    assert_eq!(format!("{:.3}", fuel.to(imperial::gal)), "0.833 imp gal");
+    assert_eq!(format!("{:.1}", fuel.to(us::fl_oz)), "128.0 US fl oz");
+    assert_ne!(
+        us::gal.to_dyn().info().name(),
+        imperial::gal.to_dyn().info().name()
+    );
+}
+
+#[test]
+fn astronomy() {
+    let distance = 1. * u::pc;
+    assert_eq!(format!("{:.3}", distance.to(u::ly)), "3.262 ly");
+    assert_eq!(format!("{:.0}", distance.to(u::AU)), "206265 au");
+    assert_eq!(format!("{:.1}", (1. * u::Mpc).to(u::kpc)), "1000.0 kpc");
+    let age = 13.8 * u::Gyr;
+    assert_eq!(format!("{:.3e}", age.to(u::s)), "4.355e17 s");
+    let ratio = (1. * u::M_sun) / (1. * u::M_earth);
+    assert_eq!(format!("{:.3e}", ratio.simplify()), "3.330e5");
+    let flux = 3. * u::mJy;
+    assert_eq!(
+        format!("{:.1e}", flux.to(u::W / u::m / u::m / u::Hz)),
+        "3.0e-29 W m^-2 Hz^-1"
+    );
+    assert_eq!(format!("{:.0}", (1. * u::arcsec).to(u::mas)), "1000 mas");
+}
+
+#[test]
+fn particle_physics() {
+    let energy = 1. * u::GeV;
+    let wavenumber = energy.to_natural(u::s / u::m / u::s).unwrap();
+    assert_eq!(format!("{:.3e}", wavenumber), "5.068e15 m^-1");
+    let length = 1. * u::fermi;
+    assert_eq!(length.to_string(), "1 fm");
+    let inverse_energy = length.to_natural(u::GeV / u::GeV / u::GeV).unwrap();
+    assert_eq!(format!("{:.3}", inverse_energy), "5.068 GeV^-1");
+    let nucleon = 1. * u::Da;
+    assert_eq!(
+        format!("{:.1}", nucleon.to_natural(u::MeV).unwrap()),
+        "931.5 MeV"
+    );
+    let cross_section = 2. * u::fb;
+    assert_eq!(
+        format!("{:.0e}", cross_section.to(u::m * u::m)),
+        "2e-43 m^2"
+    );
+    assert_eq!(format!("{:.2}", (1. * u::Eh).to(u::eV)), "27.21 eV");
+}
+
+#[test]
+fn everyday_units() {
+    let trip = 90. * u::kmh * (2. * u::h);
+    assert_eq!(format!("{:.0}", trip.to(u::km)), "180 km");
+    assert_eq!(format!("{:.0}", (1. * u::d).to(u::s)), "86400 s");
+    assert_eq!(format!("{:.2}", (1. * u::wk).to(u::h)), "168.00 h");
+    assert_eq!(format!("{:.4}", (1. * u::yr).to(u::d)), "365.2500 d");
+    assert_eq!(format!("{:.1}", (1. * u::atm).to(u::torr)), "760.0 Torr");
+    assert_eq!(format!("{:.1}", (1. * u::atm).to(u::mmHg)), "760.0 mmHg");
+    assert_eq!(format!("{:.2}", (1. * u::bar).to(u::atm)), "0.99 atm");
+    assert_eq!(format!("{:.1}", (1. * u::kWh).to(u::MJ)), "3.6 MJ");
+    assert_eq!(format!("{:.3}", (1. * u::kcal).to(u::kJ)), "4.184 kJ");
+    assert_eq!(format!("{:.3}", (10. * u::kn).to(u::kmh)), "18.520 km/h");
+    assert_eq!(format!("{:.0}", (2. * u::ha).to(u::m * u::m)), "20000 m^2");
+    assert_eq!(format!("{:.0}", (1.5 * u::L).to(u::mL)), "1500 mL");
+    assert_eq!(format!("{:.0}", (3. * u::t).to(u::kg)), "3000 kg");
+    assert_eq!(format!("{:.0}", (3000. * u::rpm).to(u::Hz)), "50 Hz");
+}
+
+#[test]
+fn constants() {
+    use rus::constants::{self, Codata};
+    let rest_energy = constants::m_e * constants::c * constants::c;
+    assert_eq!(format!("{:.1}", rest_energy.to(u::keV)), "511.0 keV");
+    let gas_constant = constants::N_A * constants::k_B;
+    assert_eq!(
+        format!("{:.4}", gas_constant.to_si()),
+        "8.3145 m^2 kg s^-2 K^-1 mol^-1"
+    );
+    let g_2014 = constants::edition(Codata::Codata2014).G;
+    assert!(g_2014.scalar().value < *constants::G.scalar());
+    assert_eq!(
+        constants::edition(Codata::Codata2022).G.scalar().sigma,
+        1.5e-15
+    );
+    let old = constants::edition(Codata::Codata2014);
+    let energy = *(old.h * old.c).scalar();
+    let (planck, sigma) = constants::values(Codata::Codata2014).h;
+    let relative = (sigma / planck) as f32;
+    assert!((energy.relative() - relative).abs() < relative * 1e-3);
+    let fine_structure = 2. * constants::alpha;
+    assert_eq!(format!("{:.5}", fine_structure), "0.01459");
+}
+
+#[test]
+fn uncertainty() {
+    use rus::scalar::Uncertain;
+    use rus::typenum::P2;
+    let width = Uncertain::new(30., 3.) * u::cm;
+    let height = Uncertain::new(0.4, 0.04) * u::m;
+    let perimeter = width + height;
+    assert_eq!(format!("{perimeter:.1}"), "70.0 ± 5.0 cm");
+    let area = (width * height).to(u::m * u::m);
+    assert_eq!(format!("{area:.3}"), "0.120 ± 0.017 m^2");
+    let square = width.powi(P2::new());
+    assert_eq!(format!("{square:.0}"), "900 ± 180 cm^2");
+    let time = Uncertain::new(2., 0.1) * u::s;
+    let speed = (Uncertain::new(10., 0.) * u::m) / time;
+    assert_eq!(format!("{speed:.2}"), "5.00 ± 0.25 m s^-1");
+}
+
+#[test]
+fn intervals() {
+    use rus::scalar::Interval;
+    use rus::typenum::P2;
+    let housing = Interval::around(50., 0.1) * u::mm;
+    let parts = Interval::around(2., 0.01) * u::cm + Interval::around(29.5, 0.05) * u::mm;
+    let clearance = housing - parts;
+    assert!(clearance.contains(&(0.5 * u::mm)));
+    assert!(clearance.contains(&(0.25 * u::mm)));
+    assert!(!clearance.contains(&(0.9 * u::mm)));
+    assert_eq!(format!("{:.2}", clearance.width()), "0.50 mm");
+    assert_eq!(format!("{:.1}", clearance.to(u::um)), "[250.0, 750.0] μm");
+    let swing = Interval::new(-2., 3.) * u::m;
+    assert_eq!(format!("{:.1}", swing.powi(P2::new())), "[0.0, 9.0] m^2");
+    let ratio = (Interval::point(1.) * u::m) / (Interval::new(-1., 1.) * u::m);
+    assert_eq!(ratio.to_string(), "[-inf, inf]");
+}
+
+#[test]
+fn derivatives() {
+    use rus::scalar::Dual;
+    use rus::typenum::P2;
+    let speed = Dual::variable(3.) * u::m / u::s;
+    let half_mass = Dual::constant(1.) * u::kg;
+    let energy = half_mass * speed.powi(P2::new());
+    assert_eq!(format!("{:.0}", energy.value().to(u::J)), "9 J");
+    let momentum = energy.derivative(speed);
+    assert_eq!(momentum.to_string(), "6 kg m s^-1");
+    let t = Dual::variable(1.5) * u::h;
+    let rate = Dual::constant(20.) * u::km / t;
+    assert_eq!(format!("{:.2}", rate.derivative(t)), "-8.89 km h^-2");
+    let t = Dual::variable(90.) * u::min;
+    let rate = Dual::constant(20.) * u::km / t;
+    let slope = rate.derivative(t).to(u::km / u::h / u::h);
+    assert_eq!(format!("{slope:.2}"), "-8.89 km h^-2");
+    let distance = Dual::constant(3.) * u::m * t;
+    assert_eq!(
+        format!("{:.4}", distance.derivative(t * t)),
+        "0.0167 m min^-1"
+    );
+}
+
+#[test]
+fn phasors() {
+    use rus::scalar::Complex;
+    use rus::typenum::N1;
+    let resistor = Complex::new(100., 0.) * u::ohm;
+    let capacitor = Complex::new(0., -100.) * u::ohm;
+    let impedance = resistor + capacitor;
+    assert_eq!(impedance.to_string(), "100 - 100j Ω");
+    assert_eq!(format!("{:.1}", impedance.abs()), "141.4 Ω");
+    assert_eq!(format!("{:.0}", impedance.arg().to(u::deg)), "-45 °");
+    let admittance = impedance.powi(N1::new());
+    assert_eq!(
+        format!("{:.3}", admittance.to(u::A / u::A / u::ohm)),
+        "0.005 + 0.005j Ω^-1"
+    );
+    assert_eq!(impedance.re().to_string(), "100 Ω");
+    assert_eq!(impedance.conj().im().to_string(), "100 Ω");
+    let source = Complex::new(10., 0.) * u::W / u::A;
+    let current = (source / impedance).to(u::A);
+    assert_eq!(format!("{:.3}", current.to(u::mA)), "50.000 + 50.000j mA");
+}
+
+#[test]
+fn integer_quantities() {
+    use rus::imperial;
+    use rus::scalar::Rounding;
+    let offset = 250_i32 * u::mV + 1_i32 * u::V;
+    assert_eq!(offset.to_string(), "1250 mV");
+    assert_eq!(offset.to(u::uV).to_string(), "1250000 μV");
+    let power = (3_i32 * u::V) * (2_i32 * u::A);
+    assert_eq!(power.to(u::mW).to_string(), "6000 mW");
+    let board = 10_i64 * imperial::inch;
+    assert_eq!(board.to(u::mm).to_string(), "254 mm");
+    let survey = 1_000_000_000_i64 * imperial::inch;
+    assert_eq!(survey.to(u::mm).to_string(), "25400000000 mm");
+    let warming = 9_000_000_i64 * imperial::delta_degF;
+    assert_eq!(warming.to(u::mK).to_string(), "5000000000 mK");
+    let tick = 7_i8 * u::ms;
+    assert_eq!(tick.checked_to(u::us, Rounding::Nearest), None);
+    let samples = 1_000_001_i32 * u::us;
+    let seconds = samples.checked_to(u::s, Rounding::Up).unwrap();
+    assert_eq!(seconds.to_string(), "2 s");
+    assert_ne!(1_i32 * u::mV, 1_i32 * u::uV);
+    assert_eq!(1_i32 * u::mV, 1000_i32 * u::uV);
+    assert_ne!(1_i32 * u::mV, 1001_i32 * u::uV);
+    assert_eq!(30_000_i16 * u::kV, 30_000_i16 * u::kV);
+    assert_eq!(12_i64 * imperial::inch, 1_i64 * imperial::ft);
+}
+
+#[test]
+#[should_panic(expected = "integer quantity overflowed when adding")]
+fn integer_overflow() {
+    let reading = 2_000_000_000_i32 * u::mV;
+    let _ = reading + reading;
+}
+
+#[test]
+#[should_panic(expected = "integer quantity of zero raised to a negative power")]
+fn integer_zero_to_negative_power() {
+    let _ = (0_i32 * u::s).powi(rus::typenum::N1::new());
+}
+
+#[test]
+fn copy_quantities() {
+    let side = 3. * u::m;
+    let area = side * side;
+    let volume = area * side;
+    assert_eq!(volume.to_string(), "27 m^3");
+    let unit = u::kg * u::m / u::s;
+    let momentum = 2. * u::g * u::km / u::s;
+    assert_eq!(momentum.to(unit).to_string(), "2 kg m s^-1");
+    assert_eq!(unit.to_string(), "kg m s^-1");
+    let many = u::m * u::g * u::s * u::A * u::K * u::mole * u::cd * u::rad;
+    assert_eq!(many.to_string(), "m g s A K mol cd rad");
+    // Units refer to their descriptions, so units and quantities stay small.
+    assert_eq!(size_of_val(&u::m), size_of::<usize>());
+    assert!(size_of_val(&volume) <= 160);
+}
+
+#[test]
+#[should_panic(expected = "at most 16 distinct units")]
+fn too_many_units() {
+    let _ = u::m
+        * u::g
+        * u::s
+        * u::A
+        * u::K
+        * u::mole
+        * u::cd
+        * u::rad
+        * u::Hz
+        * u::N
+        * u::J
+        * u::W
+        * u::V
+        * u::ohm
+        * u::Pa
+        * u::L
+        * u::bar;
+}
+
+#[test]
+fn full_composite_units() {
+    // Units which cancel make room for the units which are added.
+    let full = u::m
+        * u::g
+        * u::s
+        * u::A
+        * u::K
+        * u::mole
+        * u::cd
+        * u::rad
+        * u::km
+        * u::mm
+        * u::cm
+        * u::dm
+        * u::hm
+        * u::dam
+        * u::Mm
+        * u::Hz;
+    let cancelled = full * (u::h / u::Hz);
+    assert_eq!(
+        cancelled.to_string(),
+        "m g s A K mol cd rad km mm cm dm hm dam Mm h"
+    );
+    assert!(cancelled.checked_mul(u::d.into()).is_none());
+
+    // Quantities whose unit would not fit are written in SI base units instead.
+    let many = rus::SingleQuantity::new(full, 1.);
+    let product = many * (2. * u::min);
+    assert_eq!(format!("{product:.1e}"), "1.2e5 m^8 kg s A K mol cd");
+}
+
+#[test]
+fn parse_units() {
+    use rus::kind::{AmountKind, LuminosityKind, VolumeKind};
+    use rus::{imperial, CompositeUnit, ParseUnitError};
+    assert_eq!(
+        "kmol".parse(),
+        Ok(CompositeUnit::<AmountKind>::from(u::kmol))
+    );
+    assert_eq!(
+        "candela".parse(),
+        Ok(CompositeUnit::<LuminosityKind>::from(u::cd))
+    );
+    assert_eq!(
+        "kcandela".parse::<CompositeUnit<LuminosityKind>>(),
+        Err(ParseUnitError::UnknownUnit)
+    );
+    assert_eq!("μl".parse(), Ok(CompositeUnit::<VolumeKind>::from(u::uL)));
+    assert_eq!("m m m".parse(), Ok(u::m * u::m * u::m));
+    assert_eq!("m³".parse(), Ok(u::m * u::m * u::m));
+    assert_eq!(
+        CompositeUnit::<VolumeKind>::parse("imp fl oz", imperial::UNITS),
+        Ok(imperial::fl_oz.into())
+    );
+    assert_eq!(
+        CompositeUnit::<VolumeKind>::parse("US gal", rus::us_customary::UNITS),
+        Ok(rus::us_customary::gal.into())
+    );
+    assert_eq!(
+        "m^x".parse::<CompositeUnit<VolumeKind>>(),
+        Err(ParseUnitError::InvalidPower)
+    );
+    for unit in [
+        u::m * u::m * u::m,
+        u::L * u::m / u::m,
+        u::cm * u::cm * u::cm,
+    ] {
+        assert_eq!(unit.to_string().parse(), Ok(unit));
+        assert_eq!(format!("{unit:#}").parse(), Ok(unit));
+    }
+}